@@ -0,0 +1,223 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Star};
+
+const HEADER: &str = "\
+# Recorded answers of advent_of_code, one per line:
+# year<TAB>day<TAB>star<TAB>input fingerprint<TAB>kind<TAB>answer
+";
+
+/// The event of records from before the year column, when 2022 was the only one.
+pub const UNDATED_YEAR: u16 = 2022;
+
+struct AnswerRecord {
+    year: u16,
+    day: u8,
+    star: Star,
+    fingerprint: u64,
+    answer: Answer,
+}
+
+/// Answers recorded for a year, day, star and input. The input is identified by its
+/// fingerprint so answers of a different puzzle input are never compared.
+pub struct AnswerRegistry {
+    records: Vec<AnswerRecord>,
+}
+
+impl AnswerRegistry {
+    /// Loads the registry from `path`, a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(why) if why.kind() == ErrorKind::NotFound => String::new(),
+            Err(why) => return Err(Error::new(format!("failed to read: {}", why)).in_file(path)),
+        };
+
+        let mut records = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let record = AnswerRecord::from_line(line)
+                .map_err(|why| why.at_line(index + 1, line).in_file(path))?;
+            records.push(record);
+        }
+
+        Ok(Self { records })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut content = String::from(HEADER);
+        for record in self.records.iter() {
+            content.push_str(&record.to_line());
+            content.push('\n');
+        }
+
+        fs::write(path, content)
+            .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(path))
+    }
+
+    pub fn get(&self, year: u16, day: u8, star: Star, fingerprint: u64) -> Option<&Answer> {
+        self.records
+            .iter()
+            .find(|record| record.matches(year, day, star, fingerprint))
+            .map(|record| &record.answer)
+    }
+
+    /// Records `answer`, replacing an earlier answer for the same year, day,
+    /// star and input.
+    pub fn insert(&mut self, year: u16, day: u8, star: Star, fingerprint: u64, answer: Answer) {
+        self.records
+            .retain(|record| !record.matches(year, day, star, fingerprint));
+        self.records.push(AnswerRecord {
+            year,
+            day,
+            star,
+            fingerprint,
+            answer,
+        });
+        self.records.sort_by_key(|record| {
+            (
+                record.year,
+                record.day,
+                record.star.number(),
+                record.fingerprint,
+            )
+        });
+    }
+}
+
+impl AnswerRecord {
+    fn matches(&self, year: u16, day: u8, star: Star, fingerprint: u64) -> bool {
+        self.year == year && self.day == day && self.star == star && self.fingerprint == fingerprint
+    }
+
+    fn from_line(line: &str) -> Result<Self> {
+        let mut fields: Vec<&str> = line.split('\t').collect();
+        let year = match fields.len() {
+            6 => {
+                let year = fields.remove(0);
+                year.parse::<u16>()
+                    .map_err(|_| Error::new(format!("invalid year '{}'", year)))?
+            }
+            //Written before answers had a year
+            5 => UNDATED_YEAR,
+            count => {
+                return Err(Error::new(format!(
+                    "expected 6 tab separated fields but found {}",
+                    count
+                )))
+            }
+        };
+
+        let day = fields[0]
+            .parse::<u8>()
+            .map_err(|_| Error::new(format!("invalid day '{}'", fields[0])))?;
+        let star = match fields[1] {
+            "1" => Star::One,
+            "2" => Star::Two,
+            _ => return Err(Error::new(format!("invalid star '{}'", fields[1]))),
+        };
+        let fingerprint = u64::from_str_radix(fields[2], 16)
+            .map_err(|_| Error::new(format!("invalid fingerprint '{}'", fields[2])))?;
+        let value = unescape(fields[4]);
+        let answer = match fields[3] {
+            "number" => Answer::Number(
+                value
+                    .parse()
+                    .map_err(|_| Error::new(format!("invalid number '{}'", value)))?,
+            ),
+            "text" => Answer::Text(value),
+            "bitmap" => Answer::Bitmap(value.split('\n').map(String::from).collect()),
+            kind => return Err(Error::new(format!("unknown answer kind '{}'", kind))),
+        };
+
+        Ok(Self {
+            year,
+            day,
+            star,
+            fingerprint,
+            answer,
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{:016x}\t{}\t{}",
+            self.year,
+            self.day,
+            self.star.number(),
+            self.fingerprint,
+            self.answer.kind(),
+            escape(&self.answer.to_string())
+        )
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for letter in value.chars() {
+        match letter {
+            '\\' => result.push_str("\\\\"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            _ => result.push(letter),
+        }
+    }
+
+    result
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut letters = value.chars();
+    while let Some(letter) = letters.next() {
+        if letter != '\\' {
+            result.push(letter);
+            continue;
+        }
+
+        match letters.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_kept_apart_by_year() {
+        let mut registry = AnswerRegistry {
+            records: vec![
+                AnswerRecord::from_line("7\t1\t00000000000000ff\tnumber\t95437").unwrap(),
+            ],
+        };
+        registry.insert(2023, 7, Star::One, 0xff, Answer::Number(6440));
+
+        assert_eq!(
+            registry.get(UNDATED_YEAR, 7, Star::One, 0xff),
+            Some(&Answer::Number(95437))
+        );
+        assert_eq!(
+            registry.get(2023, 7, Star::One, 0xff),
+            Some(&Answer::Number(6440))
+        );
+        assert_eq!(registry.get(2021, 7, Star::One, 0xff), None);
+        assert_eq!(
+            registry.records[0].to_line(),
+            "2022\t7\t1\t00000000000000ff\tnumber\t95437"
+        );
+        assert!(AnswerRecord::from_line("2022\t7\t1").is_err());
+    }
+}
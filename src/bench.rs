@@ -0,0 +1,366 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::answers::UNDATED_YEAR;
+use crate::cli::{BenchOptions, RunOptions};
+use crate::error::{Error, Result};
+use crate::runner::{input_locator, selected_days, Day};
+use crate::solution::Star;
+use crate::util::{input_name, read_input};
+
+const BASELINE_HEADER: &str = "\
+# Benchmark baseline of advent_of_code, one step per line:
+# year<TAB>day<TAB>step<TAB>median in nanoseconds
+";
+
+/// Sorted durations of all timed runs of one step.
+struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self { samples }
+    }
+
+    fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    fn mean(&self) -> Duration {
+        let total: Duration = self.samples.iter().sum();
+        total / self.samples.len() as u32
+    }
+}
+
+/// Timings of one step of a day, either `parse` or a star like `star1`.
+struct Measurement {
+    day: u8,
+    step: String,
+    timings: Timings,
+}
+
+/// Median timings of an earlier benchmark, keyed by year, day and step.
+struct Baseline {
+    medians: HashMap<(u16, u8, String), Duration>,
+}
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|why| Error::new(format!("failed to read: {}", why)).in_file(path))?;
+
+        let mut medians = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (year, day, step, median) = parse_baseline_line(line)
+                .map_err(|why| why.at_line(index + 1, line).in_file(path))?;
+            medians.insert((year, day, step), median);
+        }
+
+        Ok(Self { medians })
+    }
+
+    fn save(year: u16, measurements: &[Measurement], path: &Path) -> Result<()> {
+        let mut content = String::from(BASELINE_HEADER);
+        for measurement in measurements.iter() {
+            content.push_str(&format!(
+                "{}\t{}\t{}\t{}\n",
+                year,
+                measurement.day,
+                measurement.step,
+                measurement.timings.median().as_nanos()
+            ));
+        }
+
+        fs::write(path, content)
+            .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(path))
+    }
+
+    fn get(&self, year: u16, day: u8, step: &str) -> Option<Duration> {
+        self.medians.get(&(year, day, String::from(step))).copied()
+    }
+}
+
+fn parse_baseline_line(line: &str) -> Result<(u16, u8, String, Duration)> {
+    let mut fields: Vec<&str> = line.split('\t').collect();
+    let year = match fields.len() {
+        4 => {
+            let year = fields.remove(0);
+            year.parse::<u16>()
+                .map_err(|_| Error::new(format!("invalid year '{}'", year)))?
+        }
+        //Saved before baselines had a year
+        3 => UNDATED_YEAR,
+        count => {
+            return Err(Error::new(format!(
+                "expected 4 tab separated fields but found {}",
+                count
+            )))
+        }
+    };
+
+    let day = fields[0]
+        .parse::<u8>()
+        .map_err(|_| Error::new(format!("invalid day '{}'", fields[0])))?;
+    let nanos = fields[2]
+        .parse::<u64>()
+        .map_err(|_| Error::new(format!("invalid duration '{}'", fields[2])))?;
+
+    Ok((
+        year,
+        day,
+        String::from(fields[1]),
+        Duration::from_nanos(nanos),
+    ))
+}
+
+/// Benchmarks the selected days and prints their timings, returns whether no
+/// step regressed against the baseline.
+pub fn bench(options: &RunOptions, bench_options: &BenchOptions) -> Result<bool> {
+    let locator = input_locator(options);
+    let baseline = match &bench_options.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let mut measurements = Vec::new();
+    let mut no_regressions = true;
+
+    println!(
+        "{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Min", "Median", "Mean", "Max"
+    );
+
+    for day in selected_days(options) {
+        let input = locator.resolve(day.number)?;
+        let content = read_input(&input)?;
+        let day_measurements = bench_day(
+            day,
+            content.text(),
+            &options.stars,
+            bench_options.iterations,
+        )
+        .map_err(|why| why.in_file(input_name(&input)))?;
+
+        for measurement in day_measurements {
+            let comparison = match &baseline {
+                Some(baseline) => {
+                    match baseline.get(options.year, measurement.day, &measurement.step) {
+                        Some(base) => {
+                            let median = measurement.timings.median();
+                            let change = relative_change(base, median);
+                            if is_regression(base, median, bench_options.threshold) {
+                                no_regressions = false;
+                                format!("  {:+.1}% REGRESSION", change)
+                            } else {
+                                format!("  {:+.1}%", change)
+                            }
+                        }
+                        None => String::from("  no baseline"),
+                    }
+                }
+                None => String::new(),
+            };
+
+            let timings = &measurement.timings;
+            println!(
+                "{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}{}",
+                measurement.day,
+                measurement.step,
+                format_duration(timings.min()),
+                format_duration(timings.median()),
+                format_duration(timings.mean()),
+                format_duration(timings.max()),
+                comparison
+            );
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = &bench_options.save_baseline {
+        Baseline::save(options.year, &measurements, path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    Ok(no_regressions)
+}
+
+/// Times `iterations` runs of parsing `content` and of every star, each star
+/// runs on a single parsed solution.
+fn bench_day(
+    day: &Day,
+    content: &str,
+    stars: &[Star],
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let solution = day.parse(black_box(content))?;
+        parse_samples.push(start.elapsed());
+        drop(black_box(solution));
+    }
+
+    let mut measurements = vec![Measurement {
+        day: day.number,
+        step: String::from("parse"),
+        timings: Timings::new(parse_samples),
+    }];
+
+    let solution = day.parse(black_box(content))?;
+    for star in stars.iter() {
+        let mut star_samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = solution.solve(*star)?;
+            star_samples.push(start.elapsed());
+            drop(black_box(answer));
+        }
+
+        measurements.push(Measurement {
+            day: day.number,
+            step: format!("star{}", star.number()),
+            timings: Timings::new(star_samples),
+        });
+    }
+
+    Ok(measurements)
+}
+
+/// Change from `base` to `current` in percent of `base`.
+fn relative_change(base: Duration, current: Duration) -> f64 {
+    let base = base.as_nanos() as f64;
+    if base == 0.0 {
+        return 0.0;
+    }
+
+    //Whole nanoseconds keep an exact percentage exact
+    (current.as_nanos() as f64 - base) * 100.0 / base
+}
+
+/// Whether `current` is slower than `base` by more than `threshold` percent.
+fn is_regression(base: Duration, current: Duration, threshold: f64) -> bool {
+    relative_change(base, current) > threshold
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn nanos(samples: &[u64]) -> Timings {
+        Timings::new(
+            samples
+                .iter()
+                .map(|nanos| Duration::from_nanos(*nanos))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn median_of_odd_and_even_sample_counts() {
+        assert_eq!(nanos(&[30, 10, 20]).median(), Duration::from_nanos(20));
+        assert_eq!(nanos(&[40, 10, 30, 20]).median(), Duration::from_nanos(25));
+        assert_eq!(nanos(&[7]).median(), Duration::from_nanos(7));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = env::temp_dir().join(format!("advent_of_code_baseline_{}", process::id()));
+        let measurements = [
+            Measurement {
+                day: 3,
+                step: String::from("parse"),
+                timings: nanos(&[5, 1, 3]),
+            },
+            Measurement {
+                day: 3,
+                step: String::from("star2"),
+                timings: nanos(&[2_000, 1_000]),
+            },
+        ];
+
+        Baseline::save(2022, &measurements, &path).unwrap();
+        let baseline = Baseline::load(&path);
+        fs::remove_file(&path).unwrap();
+        let baseline = baseline.unwrap();
+
+        assert_eq!(
+            baseline.get(2022, 3, "parse"),
+            Some(Duration::from_nanos(3))
+        );
+        assert_eq!(
+            baseline.get(2022, 3, "star2"),
+            Some(Duration::from_nanos(1_500))
+        );
+        assert_eq!(baseline.get(2022, 3, "star1"), None);
+        assert_eq!(baseline.get(2021, 3, "parse"), None);
+    }
+
+    #[test]
+    fn malformed_baseline_lines_are_reported() {
+        let path = env::temp_dir().join(format!("advent_of_code_malformed_{}", process::id()));
+        fs::write(
+            &path,
+            "# header\n2022\t1\tparse\t100\n2022\t1\tstar1\tslow\n",
+        )
+        .unwrap();
+        let loaded = Baseline::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        let Err(why) = loaded else {
+            panic!("expected an error");
+        };
+        assert_eq!(
+            why.to_string(),
+            format!(
+                "{}:3: invalid duration 'slow'\n    2022\t1\tstar1\tslow",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn regression_needs_more_than_the_threshold() {
+        let base = Duration::from_nanos(1_000);
+
+        assert!(!is_regression(base, Duration::from_nanos(1_100), 10.0));
+        assert!(is_regression(base, Duration::from_nanos(1_101), 10.0));
+        assert!(!is_regression(base, Duration::from_nanos(900), 0.0));
+        assert!(!is_regression(Duration::ZERO, base, 10.0));
+    }
+}
@@ -0,0 +1,765 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::output::OutputFormat;
+use crate::solution::Star;
+use crate::util::is_stdin;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code [run] [YEAR] [DAYS...] [OPTIONS]
+       advent_of_code verify [YEAR] [DAYS...] [OPTIONS]
+       advent_of_code record [YEAR] [DAYS...] [OPTIONS]
+       advent_of_code bench [YEAR] [DAYS...] [OPTIONS] [BENCH OPTIONS]
+       advent_of_code generate [YEAR] [DAYS...] [GENERATE OPTIONS]
+       advent_of_code watch [YEAR] [DAYS...] [OPTIONS] [WATCH OPTIONS]
+       advent_of_code new [YEAR] DAY [OPTIONS]
+       advent_of_code batch [YEAR] DAY DIR [OPTIONS]
+       advent_of_code report [YEAR] [DAYS...] [OPTIONS] [REPORT OPTIONS]
+       advent_of_code fetch [YEAR] DAY [OPTIONS] [FETCH OPTIONS]
+       advent_of_code help
+
+Commands:
+  run               Run the solvers of the selected days and print their
+                    answers (default)
+  verify            Compare the answers with the recorded answers for the
+                    same input and report PASS, FAIL or NEW
+  record            Record the current answers
+  bench             Time the parsing and each star of the selected days
+  generate          Generate random inputs for the selected days
+  watch             Run the selected days again whenever one of their input
+                    files changes, until interrupted
+  new               Add a module for a new day to src/yYEAR/, register it
+                    with the runner and create its empty input file at the
+                    first location the input would be searched at. A new
+                    YEAR gets its module too
+  batch             Run a single day on every file in DIR and print a table
+                    of the answers, timings and errors of each file
+  report            Write a Markdown or HTML report with the answers, timings
+                    and input sizes of the selected days and drawings like
+                    the CRT of day 10
+  fetch             Download the input of DAY over plain HTTP to the path
+                    its input is found at, or the first location it would
+                    be searched at. An existing input is never downloaded
+                    again, an empty one is
+  help              Print this help
+
+Arguments:
+  YEAR              Event of the days, like 2022 (default: the latest event)
+  DAYS              Days to run: a single day (7), an inclusive range (3..=6),
+                    a half-open range (3..6), a comma separated list (1,5,9)
+                    or 'all' (default)
+
+Options:
+  -s, --star <1|2>            Only run the given star of each day
+  -i, --input <[DAY=]PATH>    Read the input of DAY (or of every selected day)
+                              from PATH, can be given multiple times. A PATH
+                              of '-' reads the input from stdin, like in
+                              cat day6.input | advent_of_code run 6 -i -
+  -d, --input-dir <DIR>       Look for YEAR/dayN.input files in DIR
+  -a, --answers <PATH>        Answers file of verify and record
+                              (default: answers.tsv)
+  -j, --jobs <N>              Solve up to N days in parallel, answers are
+                              still printed in day order (default: 1)
+  -f, --format <FORMAT>       Output format of run: text (default), json or
+                              csv. json and csv list every day and star with
+                              its answer, type, elapsed time and error
+  -v, --verbose               Log what the solvers do to stderr, repeat it
+                              for more detail: -v shows the input and timings
+                              of each day, -vv intermediate results and -vvv
+                              every step, like each move of day 5
+  --log-days <DAYS>           Only log messages of these days (default: all)
+  -h, --help                  Print this help
+
+Bench options:
+  -n, --iterations <N>        Number of timed runs of every step (default: 10)
+  --save-baseline <PATH>      Save the median timings to PATH
+  --baseline <PATH>           Compare the median timings with the baseline
+                              saved at PATH
+  --threshold <PERCENT>       Slowdown against the baseline reported as a
+                              regression (default: 10)
+
+Generate options:
+  --seed <N>                  Seed of the random numbers, the same seed gives
+                              the same inputs (default: 1)
+  --size <N>                  Size of the inputs, like the number of lines or
+                              the width of a grid (default: 100)
+  -o, --output <DIR>          Write YEAR/dayN.input files to DIR instead of
+                              printing the input of a single day, existing
+                              files are never overwritten
+
+Report options:
+  -o, --output <PATH>         File to write the report to, HTML for .html and
+                              .htm files, Markdown otherwise
+                              (default: report.md)
+
+Fetch options:
+  --base-url <URL>            Download BASE/YEAR/day/DAY/input from this
+                              http:// URL, like a local proxy (default:
+                              $AOC_BASE_URL). The session token is sent in
+                              cleartext, so there is no default server
+  --session-file <PATH>       Read the session token from PATH (default:
+                              $AOC_SESSION, otherwise the file .aoc-session)
+
+Watch options:
+  --interval <MS>             Milliseconds between two checks of the input
+                              files (default: 500)
+
+Input files are searched in this order, the first existing file is used:
+  1. the first --input path for the day, otherwise the first --input
+     path without a day. If one is given, no other file is used, even if
+     it does not exist
+  2. DIR/YEAR/dayN.input for --input-dir DIR
+  3. $AOC_INPUT_DIR/YEAR/dayN.input
+  4. src/yYEAR/dayN.input relative to the working directory
+  5. src/yYEAR/dayN.input in the crate the binary was built from
+";
+
+pub struct RunOptions {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub stars: Vec<Star>,
+    pub input_dir: Option<PathBuf>,
+    pub input_overrides: Vec<(Option<u8>, PathBuf)>,
+    pub answers: PathBuf,
+    pub format: OutputFormat,
+    pub jobs: usize,
+    /// Number of `-v` flags, see [`crate::log::init`].
+    pub verbosity: u8,
+    /// Days whose messages are logged, all days if empty.
+    pub log_days: Vec<u8>,
+}
+
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub save_baseline: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    /// Slowdown in percent of the baseline median above which a step regressed.
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            save_baseline: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+pub struct GenerateOptions {
+    pub seed: u64,
+    pub size: usize,
+    pub output_dir: Option<PathBuf>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            seed: 1,
+            size: 100,
+            output_dir: None,
+        }
+    }
+}
+
+pub struct ReportOptions {
+    pub output: PathBuf,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            output: PathBuf::from("report.md"),
+        }
+    }
+}
+
+pub struct BatchOptions {
+    /// Directory with the input files.
+    pub dir: PathBuf,
+}
+
+pub struct FetchOptions {
+    /// See [`crate::fetch::Endpoint::from_options`] for the defaults.
+    pub base_url: Option<String>,
+    pub session_file: Option<PathBuf>,
+}
+
+pub struct WatchOptions {
+    pub interval: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+        }
+    }
+}
+
+pub enum Command {
+    Run(RunOptions),
+    Verify(RunOptions),
+    Record(RunOptions),
+    Bench(RunOptions, BenchOptions),
+    Generate(RunOptions, GenerateOptions),
+    Watch(RunOptions, WatchOptions),
+    /// Scaffold the single day of the options.
+    New(RunOptions),
+    Batch(RunOptions, BatchOptions),
+    Report(RunOptions, ReportOptions),
+    /// Download the input of the single day of the options.
+    Fetch(RunOptions, FetchOptions),
+    Help,
+}
+
+impl Command {
+    pub fn run_options(&self) -> Option<&RunOptions> {
+        match self {
+            Command::Run(options)
+            | Command::Verify(options)
+            | Command::Record(options)
+            | Command::Bench(options, _)
+            | Command::Generate(options, _)
+            | Command::Watch(options, _)
+            | Command::New(options)
+            | Command::Batch(options, _)
+            | Command::Report(options, _)
+            | Command::Fetch(options, _) => Some(options),
+            Command::Help => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidStar(String),
+    InvalidDays(String),
+    UnknownYear(u16, Vec<u16>),
+    UnknownDay(u16, u8, Vec<u8>),
+    DayOutOfRange(u8),
+    StdinForManyDays,
+    InvalidValue(String, String),
+    UnsupportedOption(String, &'static [&'static str]),
+    GenerateManyDaysToStdout,
+    WatchStdin,
+    NewNeedsOneDay,
+    DayExists(u16, u8),
+    BatchUsage,
+    FetchNeedsOneDay,
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownOption(option) => write!(f, "unknown option '{}'", option),
+            CliError::MissingValue(option) => write!(f, "option '{}' needs a value", option),
+            CliError::InvalidStar(star) => {
+                write!(f, "invalid star '{}', expected 1 or 2", star)
+            }
+            CliError::InvalidDays(days) => write!(f, "invalid day selection '{}'", days),
+            CliError::UnknownYear(year, available_years) => {
+                let available_years: Vec<String> = available_years
+                    .iter()
+                    .map(|year| year.to_string())
+                    .collect();
+                write!(
+                    f,
+                    "year {} is not implemented (available years: {})",
+                    year,
+                    available_years.join(", ")
+                )
+            }
+            CliError::UnknownDay(year, day, available_days) => {
+                let available_days: Vec<String> =
+                    available_days.iter().map(|day| day.to_string()).collect();
+                write!(
+                    f,
+                    "day {} of {} is not implemented (available days: {})",
+                    day,
+                    year,
+                    available_days.join(", ")
+                )
+            }
+            CliError::DayOutOfRange(day) => {
+                write!(f, "there is no day {}, expected a day from 1 to 25", day)
+            }
+            CliError::StdinForManyDays => {
+                write!(f, "stdin can only be the input of a single day")
+            }
+            CliError::InvalidValue(option, value) => {
+                write!(f, "invalid value '{}' for option '{}'", value, option)
+            }
+            CliError::UnsupportedOption(option, commands) => write!(
+                f,
+                "option '{}' is only supported by {}",
+                option,
+                commands.join(", ")
+            ),
+            CliError::GenerateManyDaysToStdout => {
+                write!(f, "generating inputs of several days needs --output")
+            }
+            CliError::WatchStdin => write!(f, "stdin can not be watched for changes"),
+            CliError::NewNeedsOneDay => write!(f, "new needs exactly one day"),
+            CliError::DayExists(year, day) => {
+                write!(f, "day {} of {} is already implemented", day, year)
+            }
+            CliError::BatchUsage => write!(f, "batch needs a single day and a directory"),
+            CliError::FetchNeedsOneDay => write!(f, "fetch needs exactly one day"),
+        }
+    }
+}
+
+/// Parses the command line arguments (without the program name) into a command.
+/// `available_days` are the days of every event with the latest event last,
+/// selected days are checked against them and returned sorted.
+pub fn parse_args(
+    args: impl IntoIterator<Item = String>,
+    available_days: &[(u16, Vec<u8>)],
+) -> Result<Command, CliError> {
+    let mut args = args.into_iter().peekable();
+
+    let command = match args.peek().map(String::as_str) {
+        Some("help") => return Ok(Command::Help),
+        Some(
+            "run" | "verify" | "record" | "bench" | "generate" | "watch" | "new" | "batch"
+            | "report" | "fetch",
+        ) => args.next(),
+        _ => None,
+    };
+
+    let mut positionals: Vec<String> = Vec::new();
+    let mut stars = vec![Star::One, Star::Two];
+    let mut input_dir = None;
+    let mut input_overrides = Vec::new();
+    let mut answers = PathBuf::from("answers.tsv");
+    let mut format = OutputFormat::Text;
+    let mut jobs = 1;
+    let mut verbosity: u8 = 0;
+    let mut log_day_selections: Vec<String> = Vec::new();
+    let mut bench_options = BenchOptions::default();
+    let mut generate_options = GenerateOptions::default();
+    let mut watch_options = WatchOptions::default();
+    let mut report_options = ReportOptions::default();
+    let mut fetch_options = FetchOptions {
+        base_url: None,
+        session_file: None,
+    };
+    let command_name = command.as_deref().unwrap_or("run");
+
+    while let Some(arg) = args.next() {
+        if let Some(commands) = commands_of_option(&arg) {
+            if !commands.contains(&command_name) {
+                return Err(CliError::UnsupportedOption(arg, commands));
+            }
+        }
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-v" | "-vv" | "-vvv" | "--verbose" => {
+                //-vv and -vvv count like giving -v two or three times
+                let count = if arg == "--verbose" { 1 } else { arg.len() - 1 };
+                verbosity = verbosity.saturating_add(count as u8);
+            }
+            "--log-days" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                log_day_selections.push(value);
+            }
+            "-s" | "--star" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                stars = vec![parse_star(&value)?];
+            }
+            "-i" | "--input" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                input_overrides.push(parse_input_override(&value));
+            }
+            "-d" | "--input-dir" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "-a" | "--answers" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                answers = PathBuf::from(value);
+            }
+            "-f" | "--format" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                format = match OutputFormat::from_name(&value) {
+                    Some(format) => format,
+                    None => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "-j" | "--jobs" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                jobs = match value.parse::<usize>() {
+                    Ok(jobs) if jobs > 0 => jobs,
+                    _ => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "-n" | "--iterations" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                bench_options.iterations = match value.parse::<usize>() {
+                    Ok(iterations) if iterations > 0 => iterations,
+                    _ => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "--save-baseline" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                bench_options.save_baseline = Some(PathBuf::from(value));
+            }
+            "--baseline" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                bench_options.baseline = Some(PathBuf::from(value));
+            }
+            "--threshold" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                bench_options.threshold = match value.parse::<f64>() {
+                    Ok(threshold) if threshold >= 0.0 => threshold,
+                    _ => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "--seed" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                generate_options.seed = match value.parse::<u64>() {
+                    Ok(seed) => seed,
+                    Err(_) => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "--size" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                generate_options.size = match value.parse::<usize>() {
+                    Ok(size) if size > 0 => size,
+                    _ => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "-o" | "--output" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                match command_name {
+                    "report" => report_options.output = PathBuf::from(value),
+                    _ => generate_options.output_dir = Some(PathBuf::from(value)),
+                }
+            }
+            "--interval" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg.clone()))?;
+                watch_options.interval = match value.parse::<u64>() {
+                    Ok(interval) if interval > 0 => Duration::from_millis(interval),
+                    _ => return Err(CliError::InvalidValue(arg, value)),
+                };
+            }
+            "--base-url" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                fetch_options.base_url = Some(value);
+            }
+            "--session-file" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                fetch_options.session_file = Some(PathBuf::from(value));
+            }
+            option if option.starts_with('-') => {
+                return Err(CliError::UnknownOption(String::from(option)))
+            }
+            _ => positionals.push(arg),
+        }
+    }
+
+    //A year can only come first, days never have four digits
+    let named_year = positionals
+        .first()
+        .filter(|first| first.len() == 4)
+        .and_then(|first| first.parse::<u16>().ok());
+    if named_year.is_some() {
+        positionals.remove(0);
+    }
+    let year = named_year
+        .or_else(|| available_days.last().map(|(year, _)| *year))
+        .unwrap_or_default();
+    let year_days = match available_days
+        .iter()
+        .find(|(available, _)| *available == year)
+    {
+        Some((_, days)) => days.as_slice(),
+        //new adds the first day of an event, fetch gets inputs of days to come
+        None if command_name == "new" || command_name == "fetch" => &[],
+        None => {
+            let years = available_days.iter().map(|(year, _)| *year).collect();
+            return Err(CliError::UnknownYear(year, years));
+        }
+    };
+
+    let batch_dir = match command_name {
+        "batch" if positionals.len() == 2 => positionals.pop().map(PathBuf::from),
+        "batch" => return Err(CliError::BatchUsage),
+        _ => None,
+    };
+
+    let mut days: Vec<u8> = Vec::new();
+    for selection in positionals.iter() {
+        days.extend(parse_day_selection(selection, year_days)?);
+    }
+    let mut log_days = Vec::new();
+    for selection in log_day_selections.iter() {
+        log_days.extend(parse_day_selection(selection, year_days)?);
+    }
+
+    if command_name == "new" {
+        days.sort_unstable();
+        days.dedup();
+        match days.as_slice() {
+            [day] if year_days.contains(day) => return Err(CliError::DayExists(year, *day)),
+            [_] => {}
+            _ => return Err(CliError::NewNeedsOneDay),
+        }
+    }
+
+    if command_name == "fetch" {
+        days.sort_unstable();
+        days.dedup();
+        if days.len() != 1 {
+            return Err(CliError::FetchNeedsOneDay);
+        }
+    }
+
+    if command_name == "batch" && days.len() != 1 {
+        return Err(CliError::BatchUsage);
+    }
+
+    if days.is_empty() {
+        days.extend_from_slice(year_days);
+    }
+
+    for day in days.iter() {
+        if command_name != "new" && command_name != "fetch" && !year_days.contains(day) {
+            return Err(CliError::UnknownDay(year, *day, year_days.to_vec()));
+        }
+        if !(1..=25).contains(day) {
+            return Err(CliError::DayOutOfRange(*day));
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+
+    let stdin_day_count = days
+        .iter()
+        .filter(|day| reads_stdin(**day, &input_overrides))
+        .count();
+    if stdin_day_count > 1 {
+        return Err(CliError::StdinForManyDays);
+    }
+
+    if command_name == "watch" && stdin_day_count > 0 {
+        return Err(CliError::WatchStdin);
+    }
+
+    if command_name == "generate" && days.len() > 1 && generate_options.output_dir.is_none() {
+        return Err(CliError::GenerateManyDaysToStdout);
+    }
+
+    let options = RunOptions {
+        year,
+        days,
+        stars,
+        input_dir,
+        input_overrides,
+        answers,
+        format,
+        jobs,
+        verbosity,
+        log_days,
+    };
+
+    match command_name {
+        "verify" => Ok(Command::Verify(options)),
+        "record" => Ok(Command::Record(options)),
+        "bench" => Ok(Command::Bench(options, bench_options)),
+        "generate" => Ok(Command::Generate(options, generate_options)),
+        "watch" => Ok(Command::Watch(options, watch_options)),
+        "new" => Ok(Command::New(options)),
+        "report" => Ok(Command::Report(options, report_options)),
+        "fetch" => Ok(Command::Fetch(options, fetch_options)),
+        "batch" => Ok(Command::Batch(
+            options,
+            BatchOptions {
+                dir: batch_dir.unwrap_or_default(),
+            },
+        )),
+        _ => Ok(Command::Run(options)),
+    }
+}
+
+/// The commands an option is supported by, `None` for options of every command.
+fn commands_of_option(option: &str) -> Option<&'static [&'static str]> {
+    match option {
+        "-f" | "--format" => Some(&["run"]),
+        "-j" | "--jobs" => Some(&["run", "verify", "record", "batch", "report"]),
+        "-n" | "--iterations" | "--save-baseline" | "--baseline" | "--threshold" => {
+            Some(&["bench"])
+        }
+        "--seed" | "--size" => Some(&["generate"]),
+        "-o" | "--output" => Some(&["generate", "report"]),
+        "--interval" => Some(&["watch"]),
+        "--base-url" | "--session-file" => Some(&["fetch"]),
+        _ => None,
+    }
+}
+
+fn parse_star(arg: &str) -> Result<Star, CliError> {
+    match arg {
+        "1" => Ok(Star::One),
+        "2" => Ok(Star::Two),
+        _ => Err(CliError::InvalidStar(String::from(arg))),
+    }
+}
+
+/// Whether the first override path for `day` is stdin.
+fn reads_stdin(day: u8, input_overrides: &[(Option<u8>, PathBuf)]) -> bool {
+    let day_override = input_overrides
+        .iter()
+        .find(|(override_day, _)| *override_day == Some(day));
+    let any_day_override = input_overrides
+        .iter()
+        .find(|(override_day, _)| override_day.is_none());
+
+    match day_override.or(any_day_override) {
+        Some((_, path)) => is_stdin(path),
+        None => false,
+    }
+}
+
+fn parse_input_override(value: &str) -> (Option<u8>, PathBuf) {
+    if let Some((day, path)) = value.split_once('=') {
+        if let Ok(day) = day.parse::<u8>() {
+            return (Some(day), PathBuf::from(path));
+        }
+    }
+
+    (None, PathBuf::from(value))
+}
+
+fn parse_day_selection(selection: &str, available_days: &[u8]) -> Result<Vec<u8>, CliError> {
+    if selection == "all" {
+        return Ok(available_days.to_vec());
+    }
+
+    let invalid = || CliError::InvalidDays(String::from(selection));
+    let parse_day = |day: &str| day.trim().parse::<u8>().map_err(|_| invalid());
+
+    let mut days = Vec::new();
+    for part in selection.split(',') {
+        if let Some((start, end)) = part.split_once("..=") {
+            days.extend(parse_day(start)?..=parse_day(end)?);
+        } else if let Some((start, end)) = part.split_once("..") {
+            days.extend(parse_day(start)?..parse_day(end)?);
+        } else {
+            days.push(parse_day(part)?);
+        }
+    }
+
+    if days.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, CliError> {
+        let available = [(2022, (1..=12).collect())];
+        parse_args(args.split_whitespace().map(String::from), &available)
+    }
+
+    #[test]
+    fn day_selections() {
+        let available: Vec<u8> = (1..=12).collect();
+        for (selection, expected) in [
+            ("3", vec![3]),
+            ("1,5", vec![1, 5]),
+            ("3..6", vec![3, 4, 5]),
+            ("3..=6", vec![3, 4, 5, 6]),
+            (" 2 ..= 3,7", vec![2, 3, 7]),
+            ("all", available.clone()),
+        ] {
+            let days = parse_day_selection(selection, &available).unwrap();
+            assert_eq!(days, expected, "{:?}", selection);
+        }
+
+        for selection in ["6..=3", "6..6", "", "x", "1,", "-1", "256", "1..x"] {
+            let why = parse_day_selection(selection, &available).unwrap_err();
+            assert_eq!(
+                why.to_string(),
+                format!("invalid day selection '{}'", selection)
+            );
+        }
+    }
+
+    #[test]
+    fn valid_arguments() {
+        for (args, days, stars, jobs) in [
+            ("", (1..=12).collect(), vec![Star::One, Star::Two], 1),
+            ("run 2022 3,1 3", vec![1, 3], vec![Star::One, Star::Two], 1),
+            ("verify 2..=4 --star 2", vec![2, 3, 4], vec![Star::Two], 1),
+            ("run 12 -s 1 --jobs 4", vec![12], vec![Star::One], 4),
+        ] {
+            let Ok(Command::Run(options) | Command::Verify(options)) = parse(args) else {
+                panic!("expected a run or verify command for {:?}", args);
+            };
+            assert_eq!(options.year, 2022, "{:?}", args);
+            assert_eq!(options.days, days, "{:?}", args);
+            assert_eq!(options.stars, stars, "{:?}", args);
+            assert_eq!(options.jobs, jobs, "{:?}", args);
+        }
+        assert!(matches!(parse("--help"), Ok(Command::Help)));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        for (args, expected) in [
+            ("run 6..=3", "invalid day selection '6..=3'"),
+            (
+                "run 0",
+                "day 0 of 2022 is not implemented \
+                 (available days: 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)",
+            ),
+            (
+                "run 13",
+                "day 13 of 2022 is not implemented \
+                 (available days: 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)",
+            ),
+            (
+                "run 2019",
+                "year 2019 is not implemented (available years: 2022)",
+            ),
+            ("run --star 3", "invalid star '3', expected 1 or 2"),
+            ("run --jobs 0", "invalid value '0' for option '--jobs'"),
+            ("run --jobs", "option '--jobs' needs a value"),
+            ("run --frobnicate", "unknown option '--frobnicate'"),
+            (
+                "run --seed 1",
+                "option '--seed' is only supported by generate",
+            ),
+            (
+                "new 2022 0",
+                "there is no day 0, expected a day from 1 to 25",
+            ),
+            ("new 3", "day 3 of 2022 is already implemented"),
+            ("new 13,14", "new needs exactly one day"),
+            ("batch 1", "batch needs a single day and a directory"),
+            (
+                "run 1,2 --input -",
+                "stdin can only be the input of a single day",
+            ),
+        ] {
+            let why = match parse(args) {
+                Ok(_) => panic!("expected an error for {:?}", args),
+                Err(why) => why,
+            };
+            assert_eq!(why.to_string(), expected, "{:?}", args);
+        }
+    }
+}
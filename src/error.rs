@@ -0,0 +1,83 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error of loading, parsing or solving a puzzle input. Errors caused by the
+/// input carry the position of the offending text, the file gets attached once
+/// the error reaches the code that knows where the input came from.
+#[derive(Debug, Clone)]
+pub struct Error {
+    message: String,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    /// Sets the 1-based column of the error, unless it is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Sets the 1-based line number and the text of the offending line, unless
+    /// they are already known.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.text = Some(String::from(text));
+        }
+
+        self
+    }
+
+    /// Sets the file the error occurred in, unless it is already known.
+    pub fn in_file(mut self, file: &Path) -> Self {
+        self.file.get_or_insert_with(|| file.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: ", file.display(), line)?,
+            (Some(file), None) => write!(f, "{}: ", file.display())?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}", self.message)?;
+
+        //An empty line is told by the message, showing it adds nothing
+        if let Some(text) = self.text.as_ref().filter(|text| !text.is_empty()) {
+            write!(f, "\n    {}", text)?;
+            if let Some(column) = self.column {
+                write!(f, "\n    {}^", " ".repeat(column.saturating_sub(1)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(why: io::Error) -> Self {
+        Error::new(why.to_string())
+    }
+}
@@ -0,0 +1,358 @@
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::{FetchOptions, RunOptions};
+use crate::error::{Error, Result};
+use crate::info;
+use crate::runner::input_locator;
+use crate::util::{is_stdin, InputLocator};
+
+/// Environment variable with the base URL of the inputs, the puzzle input of a
+/// day is at `BASE/YEAR/day/DAY/input`. There is no default: the token is sent
+/// in cleartext, so the server has to be chosen on purpose.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable with the session token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// File with the session token in the working directory, used if neither
+/// `--session-file` nor `AOC_SESSION` is given.
+pub const DEFAULT_SESSION_FILE: &str = ".aoc-session";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Server the inputs are downloaded from and the session token sent along.
+pub struct Endpoint {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl Endpoint {
+    /// The base URL from `--base-url` or `AOC_BASE_URL` and the session token
+    /// from `--session-file`, `AOC_SESSION` or [`DEFAULT_SESSION_FILE`], the
+    /// first one given wins.
+    pub fn from_options(fetch_options: &FetchOptions) -> Result<Self> {
+        let base_url = match &fetch_options.base_url {
+            Some(base_url) => base_url.clone(),
+            None => env::var(BASE_URL_ENV).map_err(|_| {
+                Error::new(format!(
+                    "no base URL, pass --base-url or set {}",
+                    BASE_URL_ENV
+                ))
+            })?,
+        };
+
+        let session = match (&fetch_options.session_file, env::var(SESSION_ENV)) {
+            (Some(path), _) => read_session(path)?,
+            (None, Ok(session)) => String::from(session.trim()),
+            (None, Err(_)) if Path::new(DEFAULT_SESSION_FILE).is_file() => {
+                read_session(Path::new(DEFAULT_SESSION_FILE))?
+            }
+            (None, Err(_)) => {
+                return Err(Error::new(format!(
+                    "no session token, pass --session-file, set {} or create {}",
+                    SESSION_ENV, DEFAULT_SESSION_FILE
+                )))
+            }
+        };
+        if session.is_empty() {
+            return Err(Error::new("the session token is empty"));
+        }
+        check_session(&session)?;
+
+        Ok(Self { base_url, session })
+    }
+
+    /// URL of the input of `day` of the event `year`.
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+}
+
+/// Refuses a token with line breaks or other control characters, they would
+/// end the cookie header and add headers of their own to the request.
+fn check_session(session: &str) -> Result<()> {
+    if session.chars().any(|letter| letter.is_control()) {
+        return Err(Error::new(
+            "the session token contains line breaks or other control characters",
+        ));
+    }
+
+    Ok(())
+}
+
+fn read_session(path: &Path) -> Result<String> {
+    let session = fs::read_to_string(path)
+        .map_err(|why| Error::new(format!("failed to read: {}", why)).in_file(path))?;
+    Ok(String::from(session.trim()))
+}
+
+/// What [`fetch_input`] did.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// A non-empty input file was already there and was left alone.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the selected day's input unless it is already cached.
+pub fn fetch(options: &RunOptions, fetch_options: &FetchOptions) -> Result<()> {
+    let endpoint = Endpoint::from_options(fetch_options)?;
+    let locator = input_locator(options);
+    let day = options.days[0];
+
+    match fetch_input(&endpoint, options.year, day, &locator)? {
+        Fetched::Cached(path) => println!(
+            "Input of day {} of {} is already at {}",
+            day,
+            options.year,
+            path.display()
+        ),
+        Fetched::Downloaded(path) => println!(
+            "Downloaded the input of day {} of {} to {}",
+            day,
+            options.year,
+            path.display()
+        ),
+    }
+
+    Ok(())
+}
+
+/// Makes sure the input of `day` exists at the path `locator` resolves it to,
+/// or at its preferred path if it resolves to none. An existing file is never
+/// downloaded again unless it is empty, like the placeholder of a new day.
+pub fn fetch_input(
+    endpoint: &Endpoint,
+    year: u16,
+    day: u8,
+    locator: &InputLocator,
+) -> Result<Fetched> {
+    let path = match locator.resolve(day) {
+        Ok(path) if !is_stdin(&path) => path,
+        _ => locator.preferred_path(day),
+    };
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = endpoint.input_url(year, day);
+    info!(day, "downloading {}", url);
+    let input = get(&url, &endpoint.session)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|why| Error::new(format!("failed to create: {}", why)).in_file(dir))?;
+    }
+    //Write next to it first, so an interrupted download is never taken for the input
+    let partial_path = path.with_extension("input.part");
+    fs::write(&partial_path, input)
+        .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(&partial_path))?;
+    fs::rename(&partial_path, &path)
+        .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(&path))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Host, port and path of a plain `http://` URL.
+#[derive(Debug, PartialEq, Eq)]
+struct Url {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+fn parse_url(url: &str) -> Result<Url> {
+    let rest = url.strip_prefix("http://").ok_or_else(|| {
+        Error::new(format!(
+            "unsupported URL '{}', only plain http:// URLs are supported",
+            url
+        ))
+    })?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse::<u16>()
+                .map_err(|_| Error::new(format!("invalid port '{}' in '{}'", port, url)))?,
+        ),
+        None => (authority, 80),
+    };
+    if host.is_empty() {
+        return Err(Error::new(format!("no host in '{}'", url)));
+    }
+
+    Ok(Url {
+        host: String::from(host),
+        port,
+        path: String::from(path),
+    })
+}
+
+/// Sends a GET request with the session cookie and returns the body of a
+/// `200 OK` answer.
+fn get(url: &str, session: &str) -> Result<String> {
+    check_session(session)?;
+    let parsed = parse_url(url)?;
+    let failed = |why: std::io::Error| Error::new(format!("request to {} failed: {}", url, why));
+
+    let mut stream = TcpStream::connect((parsed.host.as_str(), parsed.port)).map_err(failed)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(failed)?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: advent_of_code/{}\r\n\
+         Cookie: session={}\r\nConnection: close\r\n\r\n",
+        parsed.path,
+        parsed.host,
+        env!("CARGO_PKG_VERSION"),
+        session
+    );
+    stream.write_all(request.as_bytes()).map_err(failed)?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(failed)?;
+
+    let (status, body) =
+        parse_response(&response).map_err(|why| Error::new(format!("{} from {}", why, url)))?;
+    if status.split(' ').next() != Some("200") {
+        return Err(Error::new(format!("{} answered '{}'", url, status)));
+    }
+
+    String::from_utf8(body).map_err(|_| Error::new(format!("{} answered with no UTF-8 text", url)))
+}
+
+/// Splits an HTTP/1.1 response into its status, like `404 Not Found`, and its
+/// body, which may be sent in chunks.
+fn parse_response(response: &[u8]) -> Result<(String, Vec<u8>)> {
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| Error::new("incomplete HTTP header"))?;
+    let header = String::from_utf8_lossy(&response[..header_end]);
+    let body = &response[header_end + 4..];
+
+    let mut header_lines = header.split("\r\n");
+    let status = header_lines
+        .next()
+        .and_then(|status_line| status_line.split_once(' '))
+        .map(|(_, status)| String::from(status.trim()))
+        .ok_or_else(|| Error::new("invalid HTTP status line"))?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+    for line in header_lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| Error::new(format!("invalid Content-Length '{}'", value)))?,
+            );
+        }
+    }
+
+    let body = match (chunked, content_length) {
+        (true, _) => decode_chunks(body)?,
+        (false, Some(length)) if body.len() < length => {
+            return Err(Error::new(format!(
+                "truncated body, expected {} bytes but got {}",
+                length,
+                body.len()
+            )))
+        }
+        (false, Some(length)) => body[..length].to_vec(),
+        (false, None) => body.to_vec(),
+    };
+
+    Ok((status, body))
+}
+
+/// Joins the chunks of a body sent with `Transfer-Encoding: chunked`.
+fn decode_chunks(mut body: &[u8]) -> Result<Vec<u8>> {
+    let truncated = || Error::new("truncated chunked body");
+    let mut decoded = Vec::new();
+
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or_else(truncated)?;
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        //Chunk extensions follow a ';'
+        let size_text = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size_text, 16)
+            .map_err(|_| Error::new(format!("invalid chunk size '{}'", size_text)))?;
+        body = &body[line_end + 2..];
+
+        if size == 0 {
+            return Ok(decoded);
+        }
+        if body.len() < size + 2 {
+            return Err(truncated());
+        }
+        decoded.extend_from_slice(&body[..size]);
+        body = &body[size + 2..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls_are_split() {
+        assert_eq!(
+            parse_url("http://127.0.0.1:8080/aoc/2022/day/1/input").unwrap(),
+            Url {
+                host: String::from("127.0.0.1"),
+                port: 8080,
+                path: String::from("/aoc/2022/day/1/input"),
+            }
+        );
+        assert_eq!(parse_url("http://example.com").unwrap().port, 80);
+        assert!(parse_url("https://example.com").is_err());
+        assert!(parse_url("http://example.com:http/").is_err());
+    }
+
+    #[test]
+    fn session_tokens_can_not_add_headers() {
+        assert!(check_session("53616c7465645f5f").is_ok());
+        assert!(check_session("abc\r\nX-Injected: 1").is_err());
+        assert!(check_session("abc\n").is_err());
+        assert!(get("http://127.0.0.1:9/", "abc\r\nHost: evil").is_err());
+    }
+
+    #[test]
+    fn responses_are_decoded() {
+        let (status, body) =
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\n1\n2\nignored").unwrap();
+        assert_eq!((status.as_str(), body.as_slice()), ("200 OK", &b"1\n2"[..]));
+
+        let (_, body) = parse_response(
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n1000\n\r\n3;x=y\r\n20\n\r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(body, b"1000\n20\n");
+
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\n1\n").is_err());
+        assert!(
+            parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n9\r\n1").is_err()
+        );
+    }
+}
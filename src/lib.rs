@@ -0,0 +1,24 @@
+//! Solutions to Advent of Code puzzles.
+//!
+//! Every event has a module like [`y2022`] with a day module per puzzle and
+//! the registry of its days, shared helpers live in [`util`]. Every day module
+//! has a `DayN` type implementing [`solution::Solution`], some also expose the
+//! data structures of their puzzle, like [`y2022::day7::FileSystem`],
+//! [`y2022::day10::Device`] and [`y2022::day12::HeightMap`]. The
+//! `advent_of_code` binary is a thin command line front end to [`runner`].
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod fetch;
+pub mod log;
+pub mod output;
+pub mod pool;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod util;
+pub mod watch;
+pub mod y2022;
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{PoisonError, RwLock};
+
+/// How detailed a log message is, every `-v` shows one more level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// What the runner does with a day, like its input file and timings.
+    Info = 1,
+    /// Intermediate results of a solver.
+    Debug = 2,
+    /// Every single step of a solver.
+    Trace = 3,
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Which messages are logged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The most detailed level that is logged, 0 logs nothing.
+    pub max_level: u8,
+    /// Days whose messages are logged, all days if empty.
+    pub days: Vec<u8>,
+}
+
+impl Filter {
+    /// Logs nothing.
+    pub const fn off() -> Self {
+        Self {
+            max_level: 0,
+            days: Vec::new(),
+        }
+    }
+
+    /// Logs messages up to `verbosity` levels deep (`-v` is 1), only of `days`
+    /// unless it is empty.
+    pub fn new(verbosity: u8, days: &[u8]) -> Self {
+        Self {
+            max_level: verbosity.min(Level::Trace as u8),
+            days: days.to_vec(),
+        }
+    }
+
+    pub fn enabled(&self, level: Level, day: u8) -> bool {
+        level as u8 <= self.max_level && (self.days.is_empty() || self.days.contains(&day))
+    }
+}
+
+/// The filter of the process, set once by [`init`].
+static FILTER: RwLock<Filter> = RwLock::new(Filter::off());
+/// Copy of the level of [`FILTER`], so disabled levels are rejected without a lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the filter of the process, see [`Filter::new`].
+pub fn init(verbosity: u8, days: &[u8]) {
+    let filter = Filter::new(verbosity, days);
+    MAX_LEVEL.store(filter.max_level, Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(PoisonError::into_inner) = filter;
+}
+
+/// Whether messages of `day` at `level` are logged. Solvers check this before
+/// formatting anything, so disabled trace points stay cheap in hot loops.
+pub fn enabled(level: Level, day: u8) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    FILTER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .enabled(level, day)
+}
+
+/// Writes a message to stderr, stdout only gets the answers.
+pub fn write(level: Level, day: u8, message: fmt::Arguments) {
+    eprintln!("{:<5} day {}: {}", level.name(), day, message);
+}
+
+/// Logs a message of `day` at `level` if it is enabled, the arguments are like
+/// the ones of `format!` and only evaluated then.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $day:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, $day) {
+            $crate::log::write($level, $day, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($day:expr, $($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Info, $day, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($day:expr, $($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Debug, $day, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Trace, $day, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_and_days_are_filtered() {
+        let filter = Filter::new(2, &[5, 9]);
+        assert!(filter.enabled(Level::Info, 5));
+        assert!(filter.enabled(Level::Debug, 9));
+        assert!(!filter.enabled(Level::Trace, 9));
+        assert!(!filter.enabled(Level::Info, 7));
+
+        assert!(Filter::new(7, &[]).enabled(Level::Trace, 7));
+        assert!(!Filter::new(0, &[]).enabled(Level::Info, 1));
+        assert!(!Filter::off().enabled(Level::Info, 1));
+    }
+}
@@ -1,57 +1,64 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod util;
+use std::{env, process};
 
-use day10::day_10_star_1;
-use day11::day_11_star_1_and_2;
-use day12::day_12_star_1_and_2;
-use day2::day_2_star_1_and_2;
-use day3::{day_3_star_1, day_3_star_2};
-use day4::day_4_star_1_and_2;
-use day5::{day_5_star_1, day_5_star_2};
-use day6::day_6_star_1_and_2;
-use day7::day_7_star_1;
-use day8::{day_8_star_1, day_8_star_2};
-use day9::day_9_star_1_and_2;
-
-use crate::day1::{day_1_star_1, day_1_star_2};
+use advent_of_code::cli::{self, Command};
+use advent_of_code::{bench, error, fetch, log, report, runner, scaffold, watch};
 
 fn main() {
-    day_1_star_1();
-    day_1_star_2();
-
-    day_2_star_1_and_2();
-
-    day_3_star_1();
-    day_3_star_2();
-
-    day_4_star_1_and_2();
-
-    day_5_star_1();
-    day_5_star_2();
+    let command = match cli::parse_args(env::args().skip(1), &runner::available_days()) {
+        Ok(command) => command,
+        Err(why) => {
+            eprintln!("error: {}\n\n{}", why, cli::USAGE);
+            process::exit(2);
+        }
+    };
 
-    day_6_star_1_and_2();
+    if let Some(options) = command.run_options() {
+        log::init(options.verbosity, &options.log_days);
+    }
 
-    day_7_star_1();
-
-    day_8_star_1();
-    day_8_star_2();
-
-    day_9_star_1_and_2();
-
-    day_10_star_1();
-
-    day_11_star_1_and_2();
+    match command {
+        Command::Help => print!("{}", cli::USAGE),
+        Command::Run(options) => exit_on_failure(runner::run(&options)),
+        Command::Verify(options) => exit_on_failure(runner::verify(&options)),
+        Command::Record(options) => exit_on_failure(runner::record(&options)),
+        Command::Bench(options, bench_options) => {
+            exit_on_failure(bench::bench(&options, &bench_options))
+        }
+        Command::Generate(options, generate_options) => {
+            if let Err(why) = runner::generate(&options, &generate_options) {
+                eprintln!("error: {}", why);
+                process::exit(1);
+            }
+        }
+        Command::Watch(options, watch_options) => watch::watch(&options, &watch_options),
+        Command::Batch(options, batch_options) => {
+            exit_on_failure(runner::batch(&options, &batch_options))
+        }
+        Command::Report(options, report_options) => {
+            exit_on_failure(report::report(&options, &report_options))
+        }
+        Command::Fetch(options, fetch_options) => {
+            if let Err(why) = fetch::fetch(&options, &fetch_options) {
+                eprintln!("error: {}", why);
+                process::exit(1);
+            }
+        }
+        Command::New(options) => {
+            if let Err(why) = scaffold::new_day(&options) {
+                eprintln!("error: {}", why);
+                process::exit(1);
+            }
+        }
+    }
+}
 
-    day_12_star_1_and_2();
+fn exit_on_failure(result: error::Result<bool>) {
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(why) => {
+            eprintln!("error: {}", why);
+            process::exit(1);
+        }
+    }
 }
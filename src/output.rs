@@ -0,0 +1,192 @@
+use std::fmt::Write;
+
+use crate::runner::StarResult;
+use crate::solution::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "csv" => Some(OutputFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the results as a JSON array with one object per day and star:
+///
+/// `{"day": 10, "star": 2, "type": "bitmap", "answer": ["##..", ...], "elapsed_ns": 1234, "error": null}`
+///
+/// Numbers are JSON numbers, texts are strings and bitmaps are arrays of row
+/// strings. `type` and `answer` are `null` if the star failed, `error` holds
+/// the error message otherwise it is `null`.
+pub fn to_json(results: &[StarResult]) -> String {
+    let mut json = String::from("[");
+
+    for (index, result) in results.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+
+        let (kind, answer, error) = match &result.answer {
+            Ok(answer) => (json_string(answer.kind()), json_answer(answer), None),
+            Err(why) => (
+                String::from("null"),
+                String::from("null"),
+                Some(why.to_string()),
+            ),
+        };
+        let error = match error {
+            Some(error) => json_string(&error),
+            None => String::from("null"),
+        };
+
+        write!(
+            json,
+            "\n  {{\"day\": {}, \"star\": {}, \"type\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"error\": {}}}",
+            result.day,
+            result.star.number(),
+            kind,
+            answer,
+            result.elapsed.as_nanos(),
+            error
+        )
+        .expect("writing to a String does not fail");
+    }
+
+    if !results.is_empty() {
+        json.push('\n');
+    }
+    json.push_str("]\n");
+
+    json
+}
+
+/// Formats the results as CSV with a header line and one record per day and
+/// star. The rows of bitmaps are separated by line breaks inside a quoted field.
+pub fn to_csv(results: &[StarResult]) -> String {
+    let mut csv = String::from("day,star,type,answer,elapsed_ns,error\n");
+
+    for result in results.iter() {
+        let (kind, answer, error) = match &result.answer {
+            Ok(answer) => (answer.kind(), answer.to_string(), String::new()),
+            Err(why) => ("", String::new(), why.to_string()),
+        };
+
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            result.day,
+            result.star.number(),
+            kind,
+            csv_field(&answer),
+            result.elapsed.as_nanos(),
+            csv_field(&error)
+        )
+        .expect("writing to a String does not fail");
+    }
+
+    csv
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(value) => value.to_string(),
+        Answer::Text(value) => json_string(value),
+        Answer::Bitmap(rows) => {
+            let rows: Vec<String> = rows.iter().map(|row| json_string(row)).collect();
+            format!("[{}]", rows.join(", "))
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+
+    for letter in value.chars() {
+        match letter {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            letter if letter.is_control() => {
+                write!(result, "\\u{:04x}", u32::from(letter))
+                    .expect("writing to a String does not fail");
+            }
+            letter => result.push(letter),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/// Quotes a field if it contains a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if !value.contains([',', '"', '\n', '\r']) {
+        return String::from(value);
+    }
+
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::solution::Star;
+
+    fn results() -> Vec<StarResult> {
+        vec![
+            StarResult {
+                day: 10,
+                star: Star::Two,
+                answer: Ok(Answer::Bitmap(vec![
+                    String::from("#.\"#"),
+                    String::from("\\.#,"),
+                ])),
+                elapsed: Duration::from_nanos(1234),
+            },
+            StarResult {
+                day: 11,
+                star: Star::One,
+                answer: Err(Error::new("bad \"monkey\", \\ at\tend\u{1}").at_line(3, "a,b")),
+                elapsed: Duration::from_nanos(5),
+            },
+        ]
+    }
+
+    #[test]
+    fn json_escapes_strings() {
+        assert_eq!(
+            to_json(&results()),
+            "[\n  {\"day\": 10, \"star\": 2, \"type\": \"bitmap\", \
+             \"answer\": [\"#.\\\"#\", \"\\\\.#,\"], \"elapsed_ns\": 1234, \"error\": null},\n  \
+             {\"day\": 11, \"star\": 1, \"type\": null, \"answer\": null, \"elapsed_ns\": 5, \
+             \"error\": \"line 3: bad \\\"monkey\\\", \\\\ at\\tend\\u0001\\n    a,b\"}\n]\n"
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn csv_quotes_fields() {
+        assert_eq!(
+            to_csv(&results()),
+            "day,star,type,answer,elapsed_ns,error\n\
+             10,2,bitmap,\"#.\"\"#\n\\.#,\",1234,\n\
+             11,1,,,5,\"line 3: bad \"\"monkey\"\", \\ at\tend\u{1}\n    a,b\"\n"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::error::Result;
+
+/// Runs `task` for every item on up to `jobs` threads and passes the results to
+/// `consume` in the order of `items`, each one as soon as it and all earlier
+/// results are done. A panicking task gives the panic message as its result
+/// instead of taking down the other tasks.
+///
+/// No new tasks are started once `consume` fails, its error is returned after
+/// the running tasks finished.
+pub fn run_ordered<I, T>(
+    items: &[I],
+    jobs: usize,
+    task: impl Fn(&I) -> T + Sync,
+    mut consume: impl FnMut(&I, std::result::Result<T, String>) -> Result<()>,
+) -> Result<()>
+where
+    I: Sync,
+    T: Send,
+{
+    let next_item = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next_item, stopped, task) = (&next_item, &stopped, &task);
+
+            scope.spawn(move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    let index = next_item.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| task(item)))
+                        .map_err(|payload| panic_message(payload.as_ref()));
+                    if sender.send((index, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut done: Vec<Option<std::result::Result<T, String>>> =
+            items.iter().map(|_| None).collect();
+        let mut next_to_consume = 0;
+
+        for (index, result) in receiver {
+            done[index] = Some(result);
+
+            while let Some(result) = done.get_mut(next_to_consume).and_then(Option::take) {
+                if let Err(why) = consume(&items[next_to_consume], result) {
+                    stopped.store(true, Ordering::Relaxed);
+                    return Err(why);
+                }
+
+                next_to_consume += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return String::from(*message);
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("unknown panic")
+}
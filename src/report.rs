@@ -0,0 +1,325 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::bench::format_duration;
+use crate::cli::{ReportOptions, RunOptions};
+use crate::error::{Error, Result};
+use crate::pool::run_ordered;
+use crate::runner::{input_locator, panicked, selected_days, solve_stars, Day, DayResult};
+use crate::solution::{Answer, Star};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// HTML for `.html` and `.htm` files, Markdown for everything else.
+    pub fn of_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("html" | "htm") => ReportFormat::Html,
+            _ => ReportFormat::Markdown,
+        }
+    }
+}
+
+/// Runs the selected days and writes the report, returns whether every star
+/// was solved.
+pub fn report(options: &RunOptions, report_options: &ReportOptions) -> Result<bool> {
+    let locator = input_locator(options);
+    let days: Vec<&Day> = selected_days(options).collect();
+    let mut reports = Vec::with_capacity(days.len());
+
+    run_ordered(
+        &days,
+        options.jobs,
+        |day| solve_stars(day, &locator, &options.stars, true),
+        |day, report| {
+            reports.push(report.unwrap_or_else(|message| {
+                DayResult::failed(day.number, &options.stars, panicked(day, message))
+            }));
+            Ok(())
+        },
+    )?;
+
+    let path = &report_options.output;
+    let document = match ReportFormat::of_path(path) {
+        ReportFormat::Markdown => to_markdown(options.year, &reports),
+        ReportFormat::Html => to_html(options.year, &reports),
+    };
+    fs::write(path, document)
+        .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(path))?;
+    println!("Wrote report to {}", path.display());
+
+    Ok(reports.iter().all(DayResult::is_ok))
+}
+
+/// Header and one row per day of the overview table.
+fn overview(reports: &[DayResult]) -> Vec<Vec<String>> {
+    let mut rows = vec![
+        ["Day", "Input", "Parse", "Star 1", "Time", "Star 2", "Time"]
+            .map(String::from)
+            .to_vec(),
+    ];
+
+    for report in reports {
+        let mut row = vec![
+            report.day.to_string(),
+            match &report.input {
+                Some(size) => format!(
+                    "{} line{}, {} bytes",
+                    size.lines,
+                    if size.lines == 1 { "" } else { "s" },
+                    size.bytes
+                ),
+                None => String::from("-"),
+            },
+            report
+                .parse_time
+                .map_or_else(|| String::from("-"), format_duration),
+        ];
+
+        for star in [Star::One, Star::Two] {
+            //A day that failed before solving has nothing to show per star
+            let result = report.stars.iter().find(|result| result.star == star);
+            match result.filter(|_| report.error.is_none()) {
+                Some(result) => {
+                    row.push(match &result.answer {
+                        Ok(Answer::Bitmap(_)) => String::from("drawn below"),
+                        Ok(answer) => answer.to_string(),
+                        Err(_) => String::from("ERROR"),
+                    });
+                    row.push(format_duration(result.elapsed));
+                }
+                None => row.extend([String::from("-"), String::from("-")]),
+            }
+        }
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Captioned blocks of preformatted text of a day: bitmap answers, its
+/// rendering and its errors.
+fn details(report: &DayResult) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+
+    if let Some(why) = &report.error {
+        //It is the error of every star as well
+        blocks.push((String::from("Error"), why.to_string()));
+        return blocks;
+    }
+
+    for result in report.stars.iter() {
+        let star = result.star.number();
+        match &result.answer {
+            Ok(Answer::Bitmap(rows)) => blocks.push((format!("Star {}", star), rows.join("\n"))),
+            Err(why) => blocks.push((format!("Error of star {}", star), why.to_string())),
+            Ok(_) => {}
+        }
+    }
+
+    if let Some(rendering) = &report.rendering {
+        blocks.push((String::from("Rendering"), rendering.join("\n")));
+    }
+
+    blocks
+}
+
+/// Formats the reports as Markdown, an overview table followed by a section
+/// for every day with something to show in detail.
+pub fn to_markdown(year: u16, reports: &[DayResult]) -> String {
+    let mut markdown = format!("# {}\n\n", title(year));
+
+    for (index, row) in overview(reports).iter().enumerate() {
+        let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        if index == 0 {
+            markdown.push_str("| --: | --- | --: | --- | --: | --- | --: |\n");
+        }
+    }
+
+    for report in reports {
+        let blocks = details(report);
+        if blocks.is_empty() {
+            continue;
+        }
+
+        write!(markdown, "\n## Day {}\n", report.day).expect("writing to a String does not fail");
+        for (caption, text) in blocks {
+            //A fence longer than any run of backticks in the text
+            let fence = "`".repeat(longest_backtick_run(&text).max(2) + 1);
+            write!(
+                markdown,
+                "\n{}:\n\n{}text\n{}\n{}\n",
+                caption, fence, text, fence
+            )
+            .expect("writing to a String does not fail");
+        }
+    }
+
+    markdown
+}
+
+fn title(year: u16) -> String {
+    format!("Advent of Code {}", year)
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|letter| letter != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Formats the reports as a self-contained HTML page with the same content as
+/// [`to_markdown`].
+pub fn to_html(year: u16, reports: &[DayResult]) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: right; }}
+pre {{ background: #f4f4f4; padding: 0.6em; line-height: 1.1; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<table>
+",
+        title = title(year)
+    );
+
+    for (index, row) in overview(reports).iter().enumerate() {
+        let tag = if index == 0 { "th" } else { "td" };
+        let cells: Vec<String> = row
+            .iter()
+            .map(|cell| format!("<{}>{}</{}>", tag, escape_html(cell), tag))
+            .collect();
+        writeln!(html, "<tr>{}</tr>", cells.concat()).expect("writing to a String does not fail");
+    }
+    html.push_str("</table>\n");
+
+    for report in reports {
+        let blocks = details(report);
+        if blocks.is_empty() {
+            continue;
+        }
+
+        writeln!(html, "<h2>Day {}</h2>", report.day).expect("writing to a String does not fail");
+        for (caption, text) in blocks {
+            writeln!(
+                html,
+                "<p>{}:</p>\n<pre>{}</pre>",
+                escape_html(&caption),
+                escape_html(&text)
+            )
+            .expect("writing to a String does not fail");
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for letter in text.chars() {
+        match letter {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(letter),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::runner::{InputInfo, StarResult};
+
+    fn example_reports() -> Vec<DayResult> {
+        let star = |star, answer, micros| StarResult {
+            day: 10,
+            star,
+            answer: Ok(answer),
+            elapsed: Duration::from_micros(micros),
+        };
+
+        vec![
+            DayResult {
+                day: 10,
+                stars: vec![
+                    star(Star::One, Answer::Number(13140), 1),
+                    star(
+                        Star::Two,
+                        Answer::Bitmap(vec![String::from("#."), String::from(".#")]),
+                        2,
+                    ),
+                ],
+                input: Some(InputInfo {
+                    bytes: 20,
+                    lines: 3,
+                    fingerprint: 0,
+                }),
+                parse_time: Some(Duration::from_micros(5)),
+                rendering: None,
+                error: None,
+            },
+            DayResult::failed(11, &[Star::One, Star::Two], Error::new("a < b | c")),
+        ]
+    }
+
+    #[test]
+    fn markdown_has_an_overview_and_details() {
+        let markdown = to_markdown(2022, &example_reports());
+
+        assert!(markdown.starts_with("# Advent of Code 2022\n\n"));
+        assert!(markdown.contains(
+            "| 10 | 3 lines, 20 bytes | 5.0µs | 13140 | 1.0µs | drawn below | 2.0µs |\n"
+        ));
+        assert!(markdown.contains("| 11 | - | - | - | - | - | - |\n"));
+        assert!(markdown.contains("## Day 10\n\nStar 2:\n\n```text\n#.\n.#\n```\n"));
+        assert!(markdown.contains("## Day 11\n\nError:\n\n```text\na < b | c\n```\n"));
+    }
+
+    #[test]
+    fn html_is_escaped() {
+        let html = to_html(2022, &example_reports());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<tr><td>10</td><td>3 lines, 20 bytes</td>"));
+        assert!(html.contains("<pre>a &lt; b | c</pre>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(
+            ReportFormat::of_path(Path::new("out/report.html")),
+            ReportFormat::Html
+        );
+        assert_eq!(
+            ReportFormat::of_path(Path::new("report.md")),
+            ReportFormat::Markdown
+        );
+        assert_eq!(
+            ReportFormat::of_path(Path::new("report")),
+            ReportFormat::Markdown
+        );
+    }
+}
@@ -0,0 +1,595 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answers::AnswerRegistry;
+use crate::bench::format_duration;
+use crate::cli::{BatchOptions, GenerateOptions, RunOptions};
+use crate::error::{Error, Result};
+use crate::info;
+use crate::output::{to_csv, to_json, OutputFormat};
+use crate::pool::run_ordered;
+use crate::solution::{Answer, Solution, Star};
+use crate::util::{fingerprint, input_name, read_input, InputLocator, Rng};
+
+/// Parses the input text of a day into its solution, which borrows from it.
+pub type ParseFn = for<'a> fn(&'a str) -> Result<Box<dyn Solution<'a> + 'a>>;
+
+pub struct Day {
+    pub number: u8,
+    parse: ParseFn,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Day {
+    /// Registry entry of day `number`, usually created with [`day!`](crate::day).
+    pub const fn new(number: u8, parse: ParseFn, generate: fn(&mut Rng, usize) -> String) -> Self {
+        Self {
+            number,
+            parse,
+            generate,
+        }
+    }
+
+    /// Parses the input `text` of this day, the solution borrows from it.
+    pub fn parse<'a>(&self, text: &'a str) -> Result<Box<dyn Solution<'a> + 'a>> {
+        (self.parse)(text)
+    }
+
+    /// Generates a random input for this day, `size` scales it.
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+}
+
+/// Registry entry of day `$number` solved by the type `$solution` of `$module`,
+/// which also has to provide the input generator `$module::generate`.
+#[macro_export]
+macro_rules! day {
+    ($number:literal, $module:ident::$solution:ident) => {
+        $crate::runner::Day::new(
+            $number,
+            |text| {
+                Ok(Box::new(
+                    <$module::$solution as $crate::solution::Solution>::from_input(text)?,
+                ))
+            },
+            $module::generate,
+        )
+    };
+}
+
+/// The days of one event, each event has its module like [`crate::y2022`].
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(number: u16, days: &'static [Day]) -> Self {
+        Self { number, days }
+    }
+}
+
+/// Every event, the last one is run unless a command names another.
+pub const YEARS: &[Year] = &[Year::new(2022, crate::y2022::DAYS)];
+
+/// The days of every event, in the order of [`YEARS`].
+pub fn available_days() -> Vec<(u16, Vec<u8>)> {
+    YEARS
+        .iter()
+        .map(|year| {
+            (
+                year.number,
+                year.days.iter().map(|day| day.number).collect(),
+            )
+        })
+        .collect()
+}
+
+pub fn input_locator(options: &RunOptions) -> InputLocator {
+    let mut locator = InputLocator::new(options.year, options.input_dir.clone());
+    for (day, path) in options.input_overrides.iter() {
+        locator.add_override(*day, path.clone());
+    }
+
+    locator
+}
+
+/// The answers of one day for a single input.
+pub struct SolvedDay {
+    pub fingerprint: u64,
+    pub answers: Vec<(Star, Answer)>,
+}
+
+/// Solves the stars of a day and fails with the first error.
+pub fn solve_day(day: &Day, locator: &InputLocator, stars: &[Star]) -> Result<SolvedDay> {
+    let solved = solve_stars(day, locator, stars, false);
+    let input = match (solved.error, solved.input) {
+        (Some(why), _) => return Err(why),
+        (None, input) => input.expect("a day without an error has read its input"),
+    };
+
+    let mut answers = Vec::with_capacity(stars.len());
+    for result in solved.stars {
+        answers.push((result.star, result.answer?));
+    }
+
+    Ok(SolvedDay {
+        fingerprint: input.fingerprint,
+        answers,
+    })
+}
+
+/// The answer of one star together with the time it took to solve it.
+pub struct StarResult {
+    pub day: u8,
+    pub star: Star,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// The input a day was solved for.
+pub struct InputInfo {
+    pub bytes: usize,
+    pub lines: usize,
+    pub fingerprint: u64,
+}
+
+/// Everything solving the stars of a day gives.
+pub struct DayResult {
+    pub day: u8,
+    pub stars: Vec<StarResult>,
+    /// `None` if the input could not be read.
+    pub input: Option<InputInfo>,
+    /// `None` if the input could not be read or parsed.
+    pub parse_time: Option<Duration>,
+    /// See [`Solution::render`], only asked for by [`solve_stars`] on request.
+    pub rendering: Option<Vec<String>>,
+    /// Failure before the stars could be solved, like a missing input or a
+    /// parse error. It is the result of every star too.
+    pub error: Option<Error>,
+}
+
+impl DayResult {
+    /// Result of a day that failed before solving its stars.
+    pub fn failed(day: u8, stars: &[Star], why: Error) -> Self {
+        Self {
+            day,
+            stars: stars
+                .iter()
+                .map(|star| StarResult {
+                    day,
+                    star: *star,
+                    answer: Err(why.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+            input: None,
+            parse_time: None,
+            rendering: None,
+            error: Some(why),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.stars.iter().all(|result| result.answer.is_ok())
+    }
+}
+
+/// Solves the stars of a day and times its parsing and every star, errors are
+/// tied to the input file. The rendering of the solution is only made if
+/// `render` is set, drawing it can take as long as solving.
+pub fn solve_stars(day: &Day, locator: &InputLocator, stars: &[Star], render: bool) -> DayResult {
+    let input = match locator.resolve(day.number) {
+        Ok(input) => input,
+        Err(why) => return DayResult::failed(day.number, stars, why.into()),
+    };
+    let input_name = input_name(&input);
+    info!(day.number, "reading {}", input.display());
+    let content = match read_input(&input) {
+        Ok(content) => content,
+        Err(why) => return DayResult::failed(day.number, stars, why),
+    };
+    let input_info = InputInfo {
+        bytes: content.as_bytes().len(),
+        lines: content.lines().len(),
+        fingerprint: fingerprint(content.as_bytes()),
+    };
+
+    let start = Instant::now();
+    let solution = match day.parse(content.text()) {
+        Ok(solution) => solution,
+        Err(why) => {
+            let mut result = DayResult::failed(day.number, stars, why.in_file(input_name));
+            result.input = Some(input_info);
+            return result;
+        }
+    };
+    let parse_time = start.elapsed();
+    info!(day.number, "parsed in {}", format_duration(parse_time));
+
+    let results = stars
+        .iter()
+        .map(|star| {
+            let start = Instant::now();
+            let answer = solution.solve(*star);
+            let elapsed = start.elapsed();
+            info!(
+                day.number,
+                "solved star {} in {}",
+                star.number(),
+                format_duration(elapsed)
+            );
+            StarResult {
+                day: day.number,
+                star: *star,
+                answer: answer.map_err(|why| why.in_file(input_name)),
+                elapsed,
+            }
+        })
+        .collect();
+
+    DayResult {
+        day: day.number,
+        stars: results,
+        input: Some(input_info),
+        parse_time: Some(parse_time),
+        rendering: if render { solution.render() } else { None },
+        error: None,
+    }
+}
+
+/// The error of a solver of `day` that panicked with `message`.
+pub fn panicked(day: &Day, message: String) -> Error {
+    Error::new(format!(
+        "solver of day {} panicked: {}",
+        day.number, message
+    ))
+}
+
+pub fn selected_days(options: &RunOptions) -> impl Iterator<Item = &'static Day> + '_ {
+    YEARS
+        .iter()
+        .filter(|year| year.number == options.year)
+        .flat_map(|year| year.days.iter())
+        .filter(|day| options.days.contains(&day.number))
+}
+
+/// Runs the selected days on `options.jobs` threads and prints their answers
+/// in day order, see [`run_days`].
+pub fn run(options: &RunOptions) -> Result<bool> {
+    let locator = input_locator(options);
+    let days: Vec<&Day> = selected_days(options).collect();
+    run_days(&days, &locator, options)
+}
+
+/// Solves `days` and prints their answers in day order. A failing day, even a
+/// panicking one, only fails its own stars: text output prints their errors to
+/// stderr, json and csv report them per star. Returns whether all stars
+/// succeeded.
+pub fn run_days(days: &[&Day], locator: &InputLocator, options: &RunOptions) -> Result<bool> {
+    let solve = |day: &&Day| solve_stars(day, locator, &options.stars, false);
+
+    if options.format == OutputFormat::Text {
+        let mut all_solved = true;
+        run_ordered(days, options.jobs, solve, |day, results| {
+            let solved = results.unwrap_or_else(|message| {
+                DayResult::failed(day.number, &options.stars, panicked(day, message))
+            });
+            for result in solved.stars {
+                match &result.answer {
+                    Ok(answer) => print_answer(result.day, result.star, answer),
+                    Err(why) => {
+                        eprintln!(
+                            "error: Day {}, Star {}: {}",
+                            result.day,
+                            result.star.number(),
+                            why
+                        );
+                        all_solved = false;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+
+        return Ok(all_solved);
+    }
+
+    let mut results = Vec::new();
+    run_ordered(days, options.jobs, solve, |day, day_results| {
+        results.extend(
+            day_results
+                .unwrap_or_else(|message| {
+                    DayResult::failed(day.number, &options.stars, panicked(day, message))
+                })
+                .stars,
+        );
+        Ok(())
+    })?;
+
+    match options.format {
+        OutputFormat::Json => print!("{}", to_json(&results)),
+        _ => print!("{}", to_csv(&results)),
+    }
+
+    Ok(results.iter().all(|result| result.answer.is_ok()))
+}
+
+/// Compares the answers with the recorded ones, returns whether none of them
+/// failed or could not be computed.
+pub fn verify(options: &RunOptions) -> Result<bool> {
+    let locator = input_locator(options);
+    let registry = AnswerRegistry::load(&options.answers)?;
+    let days: Vec<&Day> = selected_days(options).collect();
+    let mut all_passed = true;
+
+    run_ordered(
+        &days,
+        options.jobs,
+        |day| solve_day(day, &locator, &options.stars),
+        |day, solved_day| {
+            let solved_day = match solved_day.unwrap_or_else(|message| Err(panicked(day, message)))
+            {
+                Ok(solved_day) => solved_day,
+                Err(why) => {
+                    println!("Day {}: ERROR {}", day.number, why);
+                    all_passed = false;
+                    return Ok(());
+                }
+            };
+
+            for (star, answer) in solved_day.answers.iter() {
+                let label = format!("Day {}, Star {}", day.number, star.number());
+                match registry.get(options.year, day.number, *star, solved_day.fingerprint) {
+                    Some(expected) if expected == answer => {
+                        println!("{}: PASS {}", label, inline(answer))
+                    }
+                    Some(expected) => {
+                        println!(
+                            "{}: FAIL expected {}, got {}",
+                            label,
+                            inline(expected),
+                            inline(answer)
+                        );
+                        all_passed = false;
+                    }
+                    None => println!("{}: NEW {}", label, inline(answer)),
+                }
+            }
+
+            Ok(())
+        },
+    )?;
+
+    Ok(all_passed)
+}
+
+/// Records the current answers, returns whether all of them could be computed.
+pub fn record(options: &RunOptions) -> Result<bool> {
+    let locator = input_locator(options);
+    let mut registry = AnswerRegistry::load(&options.answers)?;
+    let days: Vec<&Day> = selected_days(options).collect();
+    let mut all_recorded = true;
+
+    run_ordered(
+        &days,
+        options.jobs,
+        |day| solve_day(day, &locator, &options.stars),
+        |day, solved_day| {
+            let solved_day = match solved_day.unwrap_or_else(|message| Err(panicked(day, message)))
+            {
+                Ok(solved_day) => solved_day,
+                Err(why) => {
+                    println!("Day {}: ERROR {}", day.number, why);
+                    all_recorded = false;
+                    return Ok(());
+                }
+            };
+
+            for (star, answer) in solved_day.answers {
+                println!(
+                    "Day {}, Star {}: recorded {}",
+                    day.number,
+                    star.number(),
+                    inline(&answer)
+                );
+                registry.insert(
+                    options.year,
+                    day.number,
+                    star,
+                    solved_day.fingerprint,
+                    answer,
+                );
+            }
+
+            Ok(())
+        },
+    )?;
+
+    registry.save(&options.answers)?;
+    Ok(all_recorded)
+}
+
+/// Runs the selected day on every file of the batch directory and prints a
+/// table with the answers, timing and first error of each file. Failing files
+/// do not stop the batch, returns whether all of them were solved.
+pub fn batch(options: &RunOptions, batch_options: &BatchOptions) -> Result<bool> {
+    let day = selected_days(options)
+        .next()
+        .ok_or_else(|| Error::new("batch needs a day"))?;
+    let files = input_files(&batch_options.dir)?;
+
+    let mut rows = vec![[
+        String::from("File"),
+        String::from("Star 1"),
+        String::from("Star 2"),
+        String::from("Time"),
+        String::from("Error"),
+    ]];
+    let mut solved_count = 0;
+
+    run_ordered(
+        &files,
+        options.jobs,
+        |file| {
+            let mut locator = InputLocator::new(options.year, None);
+            locator.add_override(Some(day.number), file.clone());
+
+            let start = Instant::now();
+            let solved = solve_stars(day, &locator, &options.stars, false);
+            (solved.stars, start.elapsed())
+        },
+        |file, solved| {
+            let (results, elapsed) = solved.unwrap_or_else(|message| {
+                let solved = DayResult::failed(day.number, &options.stars, panicked(day, message));
+                (solved.stars, Duration::ZERO)
+            });
+
+            let answer_of = |star: Star| match results.iter().find(|result| result.star == star) {
+                Some(StarResult {
+                    answer: Ok(answer), ..
+                }) => inline(answer),
+                Some(StarResult { answer: Err(_), .. }) => String::from("ERROR"),
+                None => String::from("-"),
+            };
+            //Only the first line, the text of the offending line would break the table
+            let error = results
+                .iter()
+                .find_map(|result| result.answer.as_ref().err())
+                .map(|why| why.to_string().lines().next().unwrap_or("").to_string());
+
+            if error.is_none() {
+                solved_count += 1;
+            }
+            rows.push([
+                file.file_name().map_or_else(
+                    || file.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                ),
+                answer_of(Star::One),
+                answer_of(Star::Two),
+                format_duration(elapsed),
+                error.unwrap_or_default(),
+            ]);
+
+            Ok(())
+        },
+    )?;
+
+    print_table(&rows);
+    println!(
+        "\nSolved {} of {} inputs of day {}",
+        solved_count,
+        files.len(),
+        day.number
+    );
+
+    Ok(solved_count == files.len())
+}
+
+/// The files directly inside `dir`, sorted by name.
+fn input_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir)
+        .map_err(|why| Error::new(format!("failed to read: {}", why)).in_file(dir))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|why| Error::new(format!("failed to read: {}", why)).in_file(dir))?
+            .path();
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    if files.is_empty() {
+        return Err(Error::new("found no input files").in_file(dir));
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+/// Prints `rows` as left aligned columns, the first row is the header.
+fn print_table(rows: &[[String; 5]]) {
+    let mut widths = [0; 5];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for (index, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+
+        if index == 0 {
+            let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            println!("{}", rules.join("  "));
+        }
+    }
+}
+
+/// Generates random inputs of the selected days, one day is printed to stdout
+/// unless an output directory is given.
+pub fn generate(options: &RunOptions, generate_options: &GenerateOptions) -> Result<()> {
+    for day in selected_days(options) {
+        //Every day gets its own numbers, independent of the other selected days
+        let mut rng = Rng::new(generate_options.seed ^ (u64::from(day.number) << 56));
+        let input = day.generate(&mut rng, generate_options.size);
+
+        let output_dir = match &generate_options.output_dir {
+            Some(output_dir) => output_dir,
+            None => {
+                print!("{}", input);
+                continue;
+            }
+        };
+
+        //Laid out like an input directory, so --input-dir finds the files
+        let year_dir = output_dir.join(options.year.to_string());
+        let path: PathBuf = year_dir.join(format!("day{}.input", day.number));
+        if path.exists() {
+            return Err(Error::new("refusing to overwrite an existing file").in_file(&path));
+        }
+
+        fs::create_dir_all(&year_dir)
+            .and_then(|_| fs::write(&path, input))
+            .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(&path))?;
+        println!("Generated {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Formats an answer on a single line, joining the rows of bitmaps with `|`.
+fn inline(answer: &Answer) -> String {
+    match answer {
+        Answer::Bitmap(rows) => rows.join("|"),
+        _ => answer.to_string(),
+    }
+}
+
+fn print_answer(day: u8, star: Star, answer: &Answer) {
+    match answer {
+        Answer::Bitmap(_) => println!(
+            "Result of Advent of Code Day {}, Star {}:\n{}",
+            day,
+            star.number(),
+            answer
+        ),
+        _ => println!(
+            "Result of Advent of Code Day {}, Star {}: {}",
+            day,
+            star.number(),
+            answer
+        ),
+    }
+}
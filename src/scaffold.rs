@@ -0,0 +1,288 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::RunOptions;
+use crate::error::{Error, Result};
+use crate::runner::input_locator;
+use crate::util::{crate_src_dir, year_module};
+
+/// Module of a new day, `DAY` is replaced by the number of the day.
+const MODULE_TEMPLATE: &str = r#"use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::Rng;
+
+pub struct DayDAY<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for DayDAY<'a> {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        Ok(Self {
+            lines: lines.to_vec(),
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Err(Error::new(format!(
+            "star 1 is not solved yet, the input has {} lines",
+            self.lines.len()
+        )))
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Err(Error::new(format!(
+            "star 2 is not solved yet, the input has {} lines",
+            self.lines.len()
+        )))
+    }
+}
+
+/// Generates an input of `size` lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        input.push_str(&format!("{}\n", rng.range(1..=100)));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //The example input of the puzzle description
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "the example of day DAY is not filled in yet"]
+    fn example_star_1() {
+        let day = DayDAY::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(0));
+    }
+
+    #[test]
+    #[ignore = "the example of day DAY is not filled in yet"]
+    fn example_star_2() {
+        let day = DayDAY::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(0));
+    }
+}
+"#;
+
+/// Longest line rustfmt keeps on a single line.
+const MAX_WIDTH: usize = 100;
+/// Longest content of an array rustfmt keeps on a single line.
+const ARRAY_WIDTH: usize = 60;
+
+/// Declaration of the days in the module of a year.
+const DAYS_DECLARATION: &str = "pub const DAYS: &[Day] = &[";
+/// Declaration of the years in `runner.rs`.
+const YEARS_DECLARATION: &str = "pub const YEARS: &[Year] = &[";
+
+/// Adds a module for the selected day to the crate, registers it with the
+/// runner and creates its empty input file.
+pub fn new_day(options: &RunOptions) -> Result<()> {
+    let day = options.days[0];
+    let input_path = input_locator(options).preferred_path(day);
+
+    for path in add_day(&crate_src_dir(), options.year, day, &input_path)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Writes the module of `day` into the module of `year` in `src_dir`, registers
+/// it there and creates an empty input file at `input_path` unless one exists.
+/// The module of a new year is created and registered in `lib.rs` and
+/// `runner.rs`. Returns the written files, an existing module is never
+/// overwritten.
+pub fn add_day(src_dir: &Path, year: u16, day: u8, input_path: &Path) -> Result<Vec<PathBuf>> {
+    let year_dir = src_dir.join(year_module(year));
+    let module_path = year_dir.join(format!("day{}.rs", day));
+    if module_path.exists() {
+        return Err(Error::new("refusing to overwrite an existing module").in_file(&module_path));
+    }
+
+    //Prepare every change first, so a failure leaves the crate untouched
+    let year_path = year_dir.join("mod.rs");
+    let mut changes = Vec::new();
+    if year_path.exists() {
+        let year_source = read(&year_path)?;
+        let year_source = register_module(&year_source, &format!("day{}", day))
+            .and_then(|year_source| register_day(&year_source, day))
+            .map_err(|why| why.in_file(&year_path))?;
+        changes.push((year_path, year_source));
+    } else {
+        changes.push((year_path, new_year_module(year, day)));
+
+        let lib_path = src_dir.join("lib.rs");
+        let lib = register_module(&read(&lib_path)?, &year_module(year))
+            .map_err(|why| why.in_file(&lib_path))?;
+        changes.push((lib_path, lib));
+
+        let runner_path = src_dir.join("runner.rs");
+        let runner =
+            register_year(&read(&runner_path)?, year).map_err(|why| why.in_file(&runner_path))?;
+        changes.push((runner_path, runner));
+    }
+
+    create_dir(&year_dir)?;
+    write(
+        &module_path,
+        &MODULE_TEMPLATE.replace("DAY", &day.to_string()),
+    )?;
+    let mut written = vec![module_path];
+    for (path, content) in changes {
+        write(&path, &content)?;
+        written.push(path);
+    }
+
+    if !input_path.exists() {
+        if let Some(input_dir) = input_path.parent() {
+            create_dir(input_dir)?;
+        }
+        write(input_path, "")?;
+        written.push(input_path.to_path_buf());
+    }
+
+    Ok(written)
+}
+
+/// Module of a year whose first day is `day`.
+fn new_year_module(year: u16, day: u8) -> String {
+    format!(
+        "//! The puzzles of Advent of Code {}.\n\nuse crate::day;\nuse crate::runner::Day;\n\n\
+         pub mod day{};\n\n{}\n",
+        year,
+        day,
+        format_list(DAYS_DECLARATION, &[day_entry(day as u16)])
+    )
+}
+
+/// Adds `pub mod NAME;` to the sorted module declarations of `source`.
+fn register_module(source: &str, name: &str) -> Result<String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let first = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod "))
+        .ok_or_else(|| Error::new("found no module declarations"))?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|line| line.starts_with("pub mod "))
+        .count();
+
+    let mut modules = lines.split_off(first);
+    let rest = modules.split_off(count);
+    modules.push(format!("pub mod {};", name));
+    modules.sort_by_key(|module| module.trim_end_matches(';').to_string());
+
+    lines.extend(modules);
+    lines.extend(rest);
+    Ok(join_lines(&lines))
+}
+
+/// Adds day `day` to `DAYS` of the module of a year.
+fn register_day(year_source: &str, day: u8) -> Result<String> {
+    add_to_list(
+        year_source,
+        DAYS_DECLARATION,
+        "day!(",
+        day as u16,
+        day_entry,
+    )
+}
+
+/// Adds the module of `year` to `YEARS` of `runner.rs`.
+fn register_year(runner: &str, year: u16) -> Result<String> {
+    add_to_list(runner, YEARS_DECLARATION, "Year::new(", year, |year| {
+        format!("Year::new({}, crate::{}::DAYS)", year, year_module(year))
+    })
+}
+
+fn day_entry(day: u16) -> String {
+    format!("day!({}, day{}::Day{})", day, day, day)
+}
+
+/// Adds `number` to the list after `declaration`, whose entries start with
+/// `marker` followed by their number. The list is rewritten sorted by number.
+fn add_to_list(
+    source: &str,
+    declaration: &str,
+    marker: &str,
+    number: u16,
+    entry: impl Fn(u16) -> String,
+) -> Result<String> {
+    let start = source
+        .find(declaration)
+        .ok_or_else(|| Error::new(format!("found no '{}'", declaration)))?;
+    let end = start
+        + source[start..]
+            .find("];")
+            .ok_or_else(|| Error::new(format!("found no end of '{}'", declaration)))?
+        + 2;
+
+    let mut numbers = vec![number];
+    for (index, _) in source[start..end].match_indices(marker) {
+        let number = source[start + index + marker.len()..end]
+            .split(',')
+            .next()
+            .and_then(|number| number.trim().parse::<u16>().ok())
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "found an entry without a number after '{}'",
+                    marker
+                ))
+            })?;
+        numbers.push(number);
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    let entries: Vec<String> = numbers.into_iter().map(entry).collect();
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        format_list(declaration, &entries),
+        &source[end..]
+    ))
+}
+
+/// Formats an array like rustfmt, on one line if it is short enough.
+fn format_list(declaration: &str, entries: &[String]) -> String {
+    let content = entries.join(", ");
+    if content.len() <= ARRAY_WIDTH && declaration.len() + content.len() + 2 <= MAX_WIDTH {
+        return format!("{}{}];", declaration, content);
+    }
+
+    let mut result = format!("{}\n", declaration);
+    for entry in entries {
+        result.push_str(&format!("    {},\n", entry));
+    }
+    result.push_str("];");
+
+    result
+}
+
+fn join_lines(lines: &[String]) -> String {
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|why| Error::new(format!("failed to read: {}", why)).in_file(path))
+}
+
+fn create_dir(path: &Path) -> Result<()> {
+    fs::create_dir_all(path)
+        .map_err(|why| Error::new(format!("failed to create: {}", why)).in_file(path))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)
+        .map_err(|why| Error::new(format!("failed to write: {}", why)).in_file(path))
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use crate::error::Result;
+use crate::util::lines;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    One,
+    Two,
+}
+
+impl Star {
+    pub fn number(&self) -> u8 {
+        match self {
+            Star::One => 1,
+            Star::Two => 2,
+        }
+    }
+}
+
+/// The answer to one star of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Rows of a rendered image, like the CRT screen of day 10.
+    Bitmap(Vec<String>),
+}
+
+impl Answer {
+    /// Name of the kind of answer: `number`, `text` or `bitmap`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+            Answer::Bitmap(_) => "bitmap",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Bitmap(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(i64::from(value))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(i64::try_from(value).expect("Answer does not fit into an i64"))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(i64::try_from(value).expect("Answer does not fit into an i64"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A puzzle of one day: the input gets parsed once and both stars are
+/// computed from the parsed form, which may borrow from the input text `'a`.
+pub trait Solution<'a> {
+    fn parse(lines: &[&'a str]) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Parses the whole input `text`, see [`crate::util::Input`] for reading it.
+    fn from_input(text: &'a str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::parse(&lines(text))
+    }
+
+    fn part_one(&self) -> Result<Answer>;
+
+    fn part_two(&self) -> Result<Answer>;
+
+    fn solve(&self, star: Star) -> Result<Answer> {
+        match star {
+            Star::One => self.part_one(),
+            Star::Two => self.part_two(),
+        }
+    }
+
+    /// Rows of text drawing the puzzle, like the directory tree of day 7, for
+    /// days where a picture says more than the answers. `None` by default.
+    fn render(&self) -> Option<Vec<String>> {
+        None
+    }
+}
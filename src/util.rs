@@ -1,21 +1,440 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-
-pub fn load_lines_of_file(path: &str) -> Vec<String> {
-    let path = Path::new(path);
-    let file = match File::open(&path) {
-        Ok(file) => BufReader::new(file),
-        Err(why) => panic!("Failed to open file {}: {}", path.display(), why),
-    };
-    let mut lines = vec![];
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+mod grid;
+mod parse;
+mod point;
+
+pub use grid::Grid;
+pub use parse::{parse_blocks, parse_line, Block, Parser};
+pub use point::{Direction, Point, NEIGHBOURS_8};
+
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Input path that stands for the standard input.
+pub const STDIN_PATH: &str = "-";
+
+/// A puzzle input read once into a single buffer. Lines and blocks handed out
+/// by it borrow from that buffer instead of being copied.
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    /// Takes over `content` as the buffer, it has to be valid UTF-8.
+    pub fn from_bytes(content: Vec<u8>) -> Result<Self> {
+        match String::from_utf8(content) {
+            Ok(text) => Ok(Self { text }),
+            Err(why) => {
+                let content = why.as_bytes();
+                let position = why.utf8_error().valid_up_to();
+                let line_start = content[..position]
+                    .iter()
+                    .rposition(|byte| *byte == b'\n')
+                    .map_or(0, |index| index + 1);
+                let line_end = content[position..]
+                    .iter()
+                    .position(|byte| *byte == b'\n')
+                    .map_or(content.len(), |index| position + index);
+                let line_number = content[..line_start]
+                    .iter()
+                    .filter(|byte| **byte == b'\n')
+                    .count();
 
-    for line in file.lines() {
-        match line {
-            Ok(value) => lines.push(value),
-            Err(why) => panic!("Failed to read line: {}", why),
+                Err(Error::new("input is not valid UTF-8")
+                    .at_column(position - line_start + 1)
+                    .at_line(
+                        line_number + 1,
+                        &String::from_utf8_lossy(&content[line_start..line_end]),
+                    ))
+            }
         }
     }
 
+    /// Reads everything from `reader` into the buffer.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self> {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .map_err(|why| Error::new(format!("failed to read: {}", why)))?;
+
+        Self::from_bytes(content)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    pub fn lines(&self) -> Vec<&str> {
+        lines(&self.text)
+    }
+}
+
+/// Removes a UTF-8 byte order mark in front of `text`.
+pub fn strip_bom(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text)
+}
+
+/// Splits `text` into normalized lines borrowing from it. A byte order mark is
+/// dropped, lines may end in `\n` or `\r\n` and lose trailing whitespace, and
+/// blank lines at the end are left out. So a day sees the same lines whether
+/// or not its file ends in a newline.
+pub fn lines(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = strip_bom(text).lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
     lines
 }
+
+/// Splits `lines` into the blocks separated by blank lines. Every block comes
+/// with the 0-based index of its first line, runs of blank lines do not
+/// produce empty blocks.
+pub fn blocks<'l, 'a>(lines: &'l [&'a str]) -> impl Iterator<Item = (usize, &'l [&'a str])> {
+    let mut next_index = 0;
+
+    std::iter::from_fn(move || {
+        while lines.get(next_index)?.is_empty() {
+            next_index += 1;
+        }
+
+        let first_index = next_index;
+        while lines.get(next_index).is_some_and(|line| !line.is_empty()) {
+            next_index += 1;
+        }
+
+        Some((first_index, &lines[first_index..next_index]))
+    })
+}
+
+/// Reads the whole file at `path`, or the standard input if `path` is `-`.
+pub fn read_input(path: &Path) -> Result<Input> {
+    let input = if is_stdin(path) {
+        Input::from_reader(io::stdin().lock())
+    } else {
+        fs::read(path)
+            .map_err(|why| Error::new(format!("failed to read: {}", why)))
+            .and_then(Input::from_bytes)
+    };
+
+    input.map_err(|why| why.in_file(input_name(path)))
+}
+
+/// 64-bit FNV-1a hash of an input, stable across platforms and builds.
+pub fn fingerprint(content: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    hash
+}
+
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN_PATH)
+}
+
+/// Name of an input in messages, `<stdin>` for the standard input.
+pub fn input_name(path: &Path) -> &Path {
+    if is_stdin(path) {
+        return Path::new("<stdin>");
+    }
+
+    path
+}
+
+/// Parses every line with `parse` and attaches the 1-based line number to errors.
+pub fn parse_lines<T>(lines: &[&str], parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    let mut result = Vec::with_capacity(lines.len());
+
+    for (index, line) in lines.iter().enumerate() {
+        result.push(parse(line).map_err(|why| why.at_line(index + 1, line))?);
+    }
+
+    Ok(result)
+}
+
+/// Returns the 1-based column at which `token` starts, `token` has to be a
+/// slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    token.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// The `src` directory of the crate the binary was built from. The path is fixed
+/// at build time, so a moved checkout needs a rebuild before `new` finds it.
+pub fn crate_src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// Name of the module of the event `year`, like `y2022`.
+pub fn year_module(year: u16) -> String {
+    format!("y{}", year)
+}
+
+/// Finds the input file of a day of the event `year`. The candidates are tried
+/// in this order and the first existing file wins:
+///
+/// 1. an override path for that day, otherwise an override path for every
+///    day, where `-` stands for the standard input. If there is one, no other
+///    candidate is tried, even if it does not exist
+/// 2. `YEAR/dayN.input` in the input directory given on the command line
+/// 3. `YEAR/dayN.input` in the directory named by `AOC_INPUT_DIR`
+/// 4. `src/yYEAR/dayN.input` relative to the working directory, next to the
+///    module of the day
+/// 5. `src/yYEAR/dayN.input` in the crate the binary was built from
+pub struct InputLocator {
+    year: u16,
+    overrides: Vec<(Option<u8>, PathBuf)>,
+    input_dir: Option<PathBuf>,
+    env_input_dir: Option<PathBuf>,
+}
+
+impl InputLocator {
+    pub fn new(year: u16, input_dir: Option<PathBuf>) -> Self {
+        Self {
+            year,
+            overrides: Vec::new(),
+            input_dir,
+            env_input_dir: env::var_os(INPUT_DIR_ENV).map(PathBuf::from),
+        }
+    }
+
+    /// Adds an override path for a single day, or for every day if `day` is `None`.
+    pub fn add_override(&mut self, day: Option<u8>, path: PathBuf) {
+        self.overrides.push((day, path));
+    }
+
+    pub fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        let file_name = self.file_name(day);
+        let module_file_name = Path::new(&year_module(self.year)).join(format!("day{}.input", day));
+        let mut result = Vec::new();
+
+        //A path named on the command line is the only candidate, a typo in it
+        //must not fall back to another input
+        let day_override = self
+            .overrides
+            .iter()
+            .find(|(override_day, _)| *override_day == Some(day))
+            .or_else(|| {
+                self.overrides
+                    .iter()
+                    .find(|(override_day, _)| override_day.is_none())
+            });
+        if let Some((_, path)) = day_override {
+            result.push(path.clone());
+            return result;
+        }
+
+        if let Some(input_dir) = &self.input_dir {
+            result.push(input_dir.join(&file_name));
+        }
+
+        if let Some(env_input_dir) = &self.env_input_dir {
+            result.push(env_input_dir.join(&file_name));
+        }
+
+        result.push(Path::new("src").join(&module_file_name));
+        result.push(crate_src_dir().join(&module_file_name));
+
+        result
+    }
+
+    /// Where a new input file of `day` belongs: the first override path that
+    /// is not stdin, otherwise `YEAR/dayN.input` in the input directory or in
+    /// the directory named by `AOC_INPUT_DIR`, or next to the module of the day
+    /// in `src` of the crate.
+    pub fn preferred_path(&self, day: u8) -> PathBuf {
+        let overrides = self
+            .overrides
+            .iter()
+            .filter(|(override_day, _)| *override_day == Some(day))
+            .chain(
+                self.overrides
+                    .iter()
+                    .filter(|(override_day, _)| override_day.is_none()),
+            );
+
+        for (_, path) in overrides {
+            if !is_stdin(path) {
+                return path.clone();
+            }
+        }
+
+        match self.input_dir.as_ref().or(self.env_input_dir.as_ref()) {
+            Some(input_dir) => input_dir.join(self.file_name(day)),
+            None => crate_src_dir()
+                .join(year_module(self.year))
+                .join(format!("day{}.input", day)),
+        }
+    }
+
+    /// `YEAR/dayN.input`, the path of an input inside an input directory.
+    fn file_name(&self, day: u8) -> PathBuf {
+        Path::new(&self.year.to_string()).join(format!("day{}.input", day))
+    }
+
+    pub fn resolve(&self, day: u8) -> std::result::Result<PathBuf, InputNotFound> {
+        let tried = self.candidates(day);
+
+        match tried.iter().find(|path| is_stdin(path) || path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputNotFound { day, tried }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub day: u8,
+    pub tried: Vec<PathBuf>,
+}
+
+impl fmt::Display for InputNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no input file found for day {}, tried:", self.day)?;
+        for path in self.tried.iter() {
+            write!(f, "\n  {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<InputNotFound> for Error {
+    fn from(why: InputNotFound) -> Self {
+        Error::new(why.to_string())
+    }
+}
+
+/// Small xorshift64* pseudo random number generator for generated inputs. The
+/// same seed gives the same numbers on every platform and build.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        //Spread the seed with splitmix64, xorshift must not start at 0
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+
+        Self {
+            state: state.max(1),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        let span = (end - start) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as usize;
+        }
+
+        start + (self.next_u64() % (span + 1)) as usize
+    }
+
+    /// Whether an event with the given chance in percent happens.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    pub fn pick<'t, T>(&mut self, items: &'t [T]) -> &'t T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..=index));
+        }
+    }
+}
+
+/// Runs `check` on the input `generate` makes of `size` for each of the seeds
+/// `0..seeds`, a failing check is reported with its seed.
+#[cfg(test)]
+pub fn check_generated_inputs(
+    seeds: u64,
+    size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+    check: impl Fn(&str),
+) {
+    for seed in 0..seeds {
+        let input = generate(&mut Rng::new(seed), size);
+        let checked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| check(&input)));
+        if checked.is_err() {
+            panic!("check failed for the generated input of seed {}", seed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_normalized() {
+        let expected = ["a", "", "  b"];
+        for text in [
+            "a\n\n  b",
+            "a\n\n  b\n\n\n",
+            "\u{feff}a\r\n\r\n  b\r\n",
+            "a  \n \t\n  b\t\n  \n",
+        ] {
+            assert_eq!(lines(text), expected, "{:?}", text);
+        }
+        assert!(lines("\n \n").is_empty());
+    }
+
+    #[test]
+    fn input_from_reader() {
+        let input = Input::from_reader(io::Cursor::new(b"a\r\nb\n\n")).unwrap();
+        assert_eq!(input.lines(), ["a", "b"]);
+
+        let Err(why) = Input::from_reader(io::Cursor::new(b"a\nb\xff\n")) else {
+            panic!("expected an error");
+        };
+        assert_eq!(
+            why.to_string(),
+            "line 2: input is not valid UTF-8\n    b\u{fffd}\n     ^"
+        );
+    }
+
+    #[test]
+    fn named_input_paths_never_fall_back() {
+        let existing = crate_src_dir().join("lib.rs");
+        let missing = PathBuf::from("/nonexistent/typo.input");
+        let mut locator = InputLocator::new(2022, Some(crate_src_dir()));
+        locator.add_override(Some(6), missing.clone());
+        locator.add_override(None, existing.clone());
+
+        let why = locator.resolve(6).unwrap_err();
+        assert_eq!(why.tried, [missing]);
+        assert_eq!(locator.resolve(1).unwrap(), existing);
+        assert!(InputLocator::new(2022, None)
+            .resolve(6)
+            .unwrap()
+            .ends_with("y2022/day6.input"));
+    }
+}
@@ -0,0 +1,336 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::point::{Direction, Point, NEIGHBOURS_8};
+use crate::error::{Error, Result};
+
+/// A rectangular grid stored row by row. Cells are addressed either by `(x, y)`
+/// with `x` counting columns from the left and `y` counting rows from the top,
+/// or by the flat index `x + y * width`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses every character of `lines` into a cell, one line per row. All
+    /// lines need the same length, errors carry the line and column.
+    pub fn parse(lines: &[&str], mut parse_cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let width = match lines.first() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => return Err(Error::new("expected a grid")),
+        };
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            let line_width = line.chars().count();
+            if line_width != width {
+                return Err(Error::new(format!(
+                    "expected {} characters but found {}",
+                    width, line_width
+                ))
+                .at_line(index + 1, line));
+            }
+
+            for (column, character) in line.chars().enumerate() {
+                let cell = parse_cell(character)
+                    .map_err(|why| why.at_column(column + 1).at_line(index + 1, line))?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All cells row by row, a cell is at its flat index.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Flat index of `(x, y)`, `None` outside of the grid.
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(x + y * self.width)
+    }
+
+    /// `(x, y)` of the flat `index`.
+    pub fn position_of(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|index| &mut self.cells[index])
+    }
+
+    /// Point of the flat `index`.
+    pub fn point_of(&self, index: usize) -> Point {
+        Point::from_index(index, self.width)
+    }
+
+    /// Flat index of `point`, `None` outside of the grid.
+    pub fn index_of_point(&self, point: Point) -> Option<usize> {
+        point.to_index(self.width, self.height)
+    }
+
+    /// Flat index of the cell `step` away from `index`, `None` if that leaves
+    /// the grid.
+    pub fn step(&self, index: usize, step: Point) -> Option<usize> {
+        self.index_of_point(self.point_of(index) + step)
+    }
+
+    /// Flat indices of the cells above, below, left and right of `index`.
+    pub fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| self.step(index, direction.step()))
+    }
+
+    /// Flat indices of all cells surrounding `index`, diagonals included.
+    pub fn all_neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |step| self.step(index, *step))
+    }
+
+    /// Flat indices of the cells from `index` in the direction of `step` up to
+    /// the edge of the grid, without `index` itself.
+    pub fn ray(&self, index: usize, step: Point) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.step(index, step), move |index| self.step(*index, step))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of row `y` from left to right, panics if `y` is outside of the grid.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "row {} is outside of the grid", y);
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// The cells of column `x` from top to bottom, panics if `x` is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Mirrors the grid at its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).cloned());
+        }
+
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Draws every row as a line with one character per cell.
+    pub fn render(&self, draw_cell: impl Fn(&T) -> char) -> Vec<String> {
+        self.rows()
+            .map(|row| row.iter().map(&draw_cell).collect())
+            .collect()
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.index_of_point(point) {
+            Some(index) => &self.cells[index],
+            None => panic!("{:?} is outside of the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of_point(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{:?} is outside of the grid", point),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside of the grid", x, y),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside of the grid", x, y),
+        }
+    }
+}
+
+/// Prints one line per row with the cells next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(&["123", "456"], |digit| {
+            digit
+                .to_digit(10)
+                .ok_or_else(|| Error::new("expected a digit"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid[4], 5);
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.point_of(5), Point::new(2, 1));
+        assert_eq!(grid.index_of(1, 1), Some(4));
+        assert_eq!(grid.position_of(4), (1, 1));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn parse_errors_have_positions() {
+        let why = Grid::parse(&["12", "1x"], |digit| {
+            digit
+                .to_digit(10)
+                .ok_or_else(|| Error::new("expected a digit"))
+        })
+        .unwrap_err();
+        assert_eq!(why.to_string(), "line 2: expected a digit\n    1x\n     ^");
+
+        let why = Grid::parse(&["12", "123"], Ok).unwrap_err();
+        assert_eq!(
+            why.to_string(),
+            "line 2: expected 2 characters but found 3\n    123"
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = digits();
+
+        assert_eq!(grid.neighbours(0).collect::<Vec<_>>(), [3, 1]);
+        assert_eq!(grid.neighbours(4).collect::<Vec<_>>(), [1, 3, 5]);
+        assert_eq!(grid.all_neighbours(0).collect::<Vec<_>>(), [3, 1, 4]);
+        assert_eq!(grid.all_neighbours(4).count(), 5);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(
+            grid.ray(0, Direction::Right.step()).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(grid.ray(5, Point::new(-1, -1)).collect::<Vec<_>>(), [1]);
+        assert_eq!(grid.ray(2, Direction::Right.step()).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside of the grid")]
+    fn row_outside_panics() {
+        digits().row(2).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the grid")]
+    fn column_outside_panics() {
+        digits().column(3).count();
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let transposed = digits().transpose();
+
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.to_string(), "14\n25\n36\n");
+        assert_eq!(transposed.transpose(), digits());
+        assert_eq!(
+            transposed.render(|digit| if digit % 2 == 0 { '#' } else { '.' }),
+            [".#", "#.", ".#"]
+        );
+    }
+}
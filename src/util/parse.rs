@@ -0,0 +1,341 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::blocks;
+use crate::error::{Error, Result};
+
+/// A cursor over a single line that parsers consume from the front. Whitespace
+/// in front of every token is skipped, so the amount of spacing does not
+/// matter, and errors carry the column they occurred at.
+pub struct Parser<'a> {
+    line: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self { line, position: 0 }
+    }
+
+    /// The part of the line that is not parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    /// 1-based column of the next character.
+    pub fn column(&self) -> usize {
+        self.line[..self.position].chars().count() + 1
+    }
+
+    /// An error at the current column.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(message).at_column(self.column())
+    }
+
+    /// Whether only whitespace is left.
+    pub fn is_at_end(&self) -> bool {
+        self.rest().trim_start().is_empty()
+    }
+
+    pub fn whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Matches the words of `expected`, any whitespace may separate them.
+    pub fn literal(&mut self, expected: &str) -> Result<()> {
+        for word in expected.split_whitespace() {
+            self.whitespace();
+            if !self.rest().starts_with(word) {
+                return Err(self.error(format!("expected '{}'", expected)));
+            }
+            self.position += word.len();
+        }
+
+        Ok(())
+    }
+
+    /// Consumes `expected` if the line continues with it.
+    pub fn accept(&mut self, expected: &str) -> bool {
+        self.whitespace();
+        if !self.rest().starts_with(expected) {
+            return false;
+        }
+
+        self.position += expected.len();
+        true
+    }
+
+    /// The next run of non-whitespace characters.
+    pub fn word(&mut self) -> Result<&'a str> {
+        self.whitespace();
+        let rest = self.rest();
+        let length = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("expected a word"));
+        }
+
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    /// Converts the next word with `parse`, errors point at the word.
+    pub fn token<T>(&mut self, parse: impl FnOnce(&'a str) -> Result<T>) -> Result<T> {
+        self.whitespace();
+        let column = self.column();
+        parse(self.word()?).map_err(|why| why.at_column(column))
+    }
+
+    /// A number made of ASCII digits only.
+    pub fn unsigned<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.whitespace();
+        let length = digit_count(self.rest());
+        self.number(length)
+    }
+
+    /// A number with an optional `+` or `-` in front of its digits.
+    pub fn signed<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.whitespace();
+        let sign_length = usize::from(self.rest().starts_with(['+', '-']));
+        let length = sign_length + digit_count(&self.rest()[sign_length..]);
+        self.number(length)
+    }
+
+    fn number<T>(&mut self, length: usize) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let text = &self.rest()[..length];
+        if !text.ends_with(|letter: char| letter.is_ascii_digit()) {
+            return Err(self.error("expected a number"));
+        }
+
+        let value = text
+            .parse()
+            .map_err(|why| self.error(format!("invalid number '{}': {}", text, why)))?;
+        self.position += length;
+
+        Ok(value)
+    }
+
+    /// Items parsed with `item` and separated by `separator`, none if the line
+    /// ends right away.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        if self.is_at_end() {
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            if !self.accept(separator) {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Fails unless only whitespace is left.
+    pub fn end(&mut self) -> Result<()> {
+        self.whitespace();
+        if !self.rest().is_empty() {
+            return Err(self.error(format!("unexpected '{}'", self.rest())));
+        }
+
+        Ok(())
+    }
+}
+
+fn digit_count(text: &str) -> usize {
+    text.len()
+        - text
+            .trim_start_matches(|letter: char| letter.is_ascii_digit())
+            .len()
+}
+
+/// Parses the whole `line` with `parse`, anything left over is an error.
+pub fn parse_line<'a, T>(
+    line: &'a str,
+    parse: impl FnOnce(&mut Parser<'a>) -> Result<T>,
+) -> Result<T> {
+    let mut parser = Parser::new(line);
+    let value = parse(&mut parser)?;
+    parser.end()?;
+
+    Ok(value)
+}
+
+/// A cursor over the lines of one block of the input, errors of its lines get
+/// their line number attached.
+pub struct Block<'l, 'a> {
+    lines: &'l [&'a str],
+    first_index: usize,
+    next: usize,
+}
+
+impl<'l, 'a> Block<'l, 'a> {
+    /// A block of `lines` starting at the 0-based line `first_index` of the
+    /// input.
+    pub fn new(lines: &'l [&'a str], first_index: usize) -> Self {
+        Self {
+            lines,
+            first_index,
+            next: 0,
+        }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.next >= self.lines.len()
+    }
+
+    /// Parses the whole next line of the block with `parse`.
+    pub fn line<T>(&mut self, parse: impl FnOnce(&mut Parser<'a>) -> Result<T>) -> Result<T> {
+        let Some(line) = self.lines.get(self.next) else {
+            let last_line = self.lines.last().copied().unwrap_or("");
+            return Err(Error::new("unexpected end of the block")
+                .at_line(self.first_index + self.lines.len(), last_line));
+        };
+
+        self.next += 1;
+        parse_line(line, parse).map_err(|why| why.at_line(self.first_index + self.next, line))
+    }
+
+    /// Parses every line left in the block with `parse`.
+    pub fn remaining_lines<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut values = Vec::with_capacity(self.lines.len() - self.next);
+        while !self.is_at_end() {
+            values.push(self.line(&mut parse)?);
+        }
+
+        Ok(values)
+    }
+
+    /// Fails if lines of the block are left.
+    pub fn end(&self) -> Result<()> {
+        match self.lines.get(self.next) {
+            Some(line) => Err(Error::new("unexpected line at the end of the block")
+                .at_line(self.first_index + self.next + 1, line)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses every block of `lines` with `parse`, blocks are separated by blank
+/// lines and have to be used up completely.
+pub fn parse_blocks<'a, T>(
+    lines: &[&'a str],
+    mut parse: impl FnMut(&mut Block<'_, 'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    let mut values = Vec::new();
+    for (first_index, block_lines) in blocks(lines) {
+        let mut block = Block::new(block_lines, first_index);
+        values.push(parse(&mut block)?);
+        block.end()?;
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_ignore_spacing() {
+        let (name, values, offset) = parse_line("  values of   x:  1,2 ,  3 at -4", |line| {
+            line.literal("values of")?;
+            let name = line.word()?;
+            let values: Vec<u32> = line.separated(",", Parser::unsigned)?;
+            line.literal("at")?;
+            Ok((name, values, line.signed::<i32>()?))
+        })
+        .unwrap();
+
+        assert_eq!((name, values, offset), ("x:", vec![1, 2, 3], -4));
+        assert_eq!(
+            parse_line("items:", |line| {
+                line.literal("items:")?;
+                line.separated(",", Parser::unsigned::<u8>)
+            })
+            .unwrap(),
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_column() {
+        let line = "move 1 from x";
+        let why = parse_line(line, |parser| {
+            parser.literal("move")?;
+            parser.unsigned::<u8>()?;
+            parser.literal("from")?;
+            parser.unsigned::<u8>()
+        })
+        .unwrap_err()
+        .at_line(1, line);
+        assert_eq!(
+            why.to_string(),
+            format!(
+                "line 1: expected a number\n    {}\n    {}^",
+                line,
+                " ".repeat(12)
+            )
+        );
+
+        let line = "add  x";
+        let why = parse_line(line, |parser| {
+            parser.literal("add")?;
+            parser.token(|_| Err::<(), _>(Error::new("bad operand")))
+        })
+        .unwrap_err()
+        .at_line(1, line);
+        assert_eq!(
+            why.to_string(),
+            format!("line 1: bad operand\n    {}\n    {}^", line, " ".repeat(5))
+        );
+
+        let why = parse_line("1 2", Parser::unsigned::<u8>).unwrap_err();
+        assert_eq!(why.to_string(), "unexpected '2'");
+        let why = parse_line("300", Parser::unsigned::<u8>).unwrap_err();
+        assert_eq!(
+            why.to_string(),
+            "invalid number '300': number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn blocks_attach_line_numbers() {
+        let lines = ["a 1", "b 2", "", "a 3", "b x"];
+        let why = parse_blocks(&lines, |block| {
+            block.line(|line| {
+                line.literal("a")?;
+                line.unsigned::<u8>()
+            })?;
+            block.line(|line| {
+                line.literal("b")?;
+                line.unsigned::<u8>()
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            why.to_string(),
+            "line 5: expected a number\n    b x\n      ^"
+        );
+    }
+}
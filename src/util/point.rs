@@ -0,0 +1,193 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A position or a vector on a 2D plane. `x` grows to the right and `y` grows
+/// downwards, like columns and rows of a [`Grid`](super::Grid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point at the flat `index` of a grid `width` cells wide.
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Self::new((index % width) as i64, (index / width) as i64)
+    }
+
+    /// Flat index of this point in a `width` x `height` grid, `None` outside
+    /// of it.
+    pub fn to_index(self, width: usize, height: usize) -> Option<usize> {
+        let x = usize::try_from(self.x).ok()?;
+        let y = usize::try_from(self.y).ok()?;
+        if x >= width || y >= height {
+            return None;
+        }
+
+        Some(x + y * width)
+    }
+
+    /// Steps along the axes between the points.
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// King moves between the points, diagonal steps included.
+    pub fn chebyshev_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Both coordinates clamped to -1, 0 or 1, a single step towards where
+    /// this vector points.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Steps to all eight surrounding points, the diagonal ones last.
+pub const NEIGHBOURS_8: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, -1),
+    Point::new(1, -1),
+    Point::new(-1, 1),
+    Point::new(1, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The vector of a single step in this direction.
+    pub fn step(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a, Point::new(-1, 2));
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!((a - b).signum(), Point::new(1, -1));
+        assert_eq!(a.to_string(), "(1, -2)");
+    }
+
+    #[test]
+    fn grid_indices() {
+        assert_eq!(Point::from_index(7, 3), Point::new(1, 2));
+        assert_eq!(Point::new(1, 2).to_index(3, 3), Some(7));
+        assert_eq!(Point::new(3, 0).to_index(3, 3), None);
+        assert_eq!(Point::new(0, -1).to_index(3, 3), None);
+    }
+
+    #[test]
+    fn rotations() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().step(), -direction.step());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::SystemTime;
+
+use crate::bench::format_duration;
+use crate::cli::{RunOptions, WatchOptions};
+use crate::runner::{input_locator, selected_days, solve_stars, Day};
+use crate::solution::Answer;
+use crate::util::InputLocator;
+
+/// Moves the cursor to the top left corner and clears the terminal.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// The input file a day resolved to and when it was modified last, `None` if
+/// it could not be found or read.
+type InputStamp = Option<(PathBuf, Option<SystemTime>)>;
+
+/// Runs the selected days and runs them again whenever the modification time
+/// of one of their input files changes, or another file becomes their input.
+/// Never returns, the user stops it with Ctrl+C.
+pub fn watch(options: &RunOptions, watch_options: &WatchOptions) -> ! {
+    let locator = input_locator(options);
+    let days: Vec<&Day> = selected_days(options).collect();
+    let mut last_stamps = None;
+
+    loop {
+        let stamps: Vec<InputStamp> = days.iter().map(|day| input_stamp(day, &locator)).collect();
+
+        if last_stamps.as_ref() != Some(&stamps) {
+            print!("{}", CLEAR_SCREEN);
+            for day in days.iter() {
+                run_day(day, &locator, options);
+            }
+
+            println!("\nWatching for changes of:");
+            for (day, stamp) in days.iter().zip(stamps.iter()) {
+                match stamp {
+                    Some((path, _)) => println!("  {}", path.display()),
+                    None => println!("  the input of day {} (not found yet)", day.number),
+                }
+            }
+            println!("Press Ctrl+C to stop.");
+
+            last_stamps = Some(stamps);
+        }
+
+        thread::sleep(watch_options.interval);
+    }
+}
+
+fn input_stamp(day: &Day, locator: &InputLocator) -> InputStamp {
+    let path = locator.resolve(day.number).ok()?;
+    let modified = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    Some((path, modified))
+}
+
+fn run_day(day: &Day, locator: &InputLocator, options: &RunOptions) {
+    for result in solve_stars(day, locator, &options.stars, false).stars {
+        let label = format!("Day {}, Star {}", result.day, result.star.number());
+        match result.answer {
+            Ok(Answer::Bitmap(rows)) => println!(
+                "{} in {}:\n{}",
+                label,
+                format_duration(result.elapsed),
+                rows.join("\n")
+            ),
+            Ok(answer) => println!(
+                "{}: {} in {}",
+                label,
+                answer,
+                format_duration(result.elapsed)
+            ),
+            Err(why) => println!("{}: ERROR {}", label, why),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{blocks, Rng};
+
+pub struct Day1 {
+    elves: Vec<u32>,
+}
+
+impl<'a> Solution<'a> for Day1 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let mut elves = Vec::new();
+
+        //Every elve carries a block of calorie counts
+        for (first_index, calories) in blocks(lines) {
+            let mut current_elve: u32 = 0;
+
+            for (offset, line) in calories.iter().enumerate() {
+                match line.parse::<u32>() {
+                    Ok(value) => {
+                        current_elve = current_elve.checked_add(value).ok_or_else(|| {
+                            Error::new("calorie total of the elve overflows")
+                                .at_line(first_index + offset + 1, line)
+                        })?;
+                    }
+                    Err(why) => {
+                        return Err(Error::new(format!(
+                            "invalid calorie count '{}': {}",
+                            line, why
+                        ))
+                        .at_column(1)
+                        .at_line(first_index + offset + 1, line))
+                    }
+                };
+            }
+
+            elves.push(current_elve);
+        }
+
+        Ok(Self { elves })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        let mut current_max: u32 = 0;
+
+        for elve in self.elves.iter() {
+            current_max = if *elve > current_max {
+                *elve
+            } else {
+                current_max
+            };
+        }
+
+        Ok(current_max.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        let mut top_three_elves = vec![0, 0, 0];
+
+        for elve in self.elves.iter() {
+            for i in 0..top_three_elves.len() {
+                if *elve > top_three_elves[i] {
+                    top_three_elves.insert(i, *elve);
+                    top_three_elves.pop();
+                    break;
+                }
+            }
+        }
+
+        Ok(top_three_elves.iter().sum::<u32>().into())
+    }
+}
+
+/// Generates the calorie lists of `size` elves.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        for _ in 0..rng.range(1..=15) {
+            input.push_str(&format!("{}\n", rng.range(1_000..=60_000)));
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day1::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(24000));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day1::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(45000));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day1::from_input(input).unwrap();
+            let (Answer::Number(most), Answer::Number(top_three)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //Every elf carries 1 to 15 items of 1,000 to 60,000 calories
+            assert!((1_000..=15 * 60_000).contains(&most));
+            assert!(most < top_three && top_three <= 3 * most);
+        });
+    }
+}
@@ -0,0 +1,383 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::util::{column_of, parse_lines, Grid, Rng};
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+pub enum Instruction {
+    AddX(i64),
+    Noop,
+}
+
+impl Instruction {
+    pub fn from_line(line: &str) -> Result<Self> {
+        let instruction_tokens: Vec<&str> = line.split_whitespace().collect();
+        match instruction_tokens[..] {
+            ["addx", value] => match value.parse() {
+                Ok(value) => Ok(Instruction::AddX(value)),
+                Err(why) => Err(Error::new(format!("invalid value '{}': {}", value, why))
+                    .at_column(column_of(line, value))),
+            },
+            ["noop"] => Ok(Instruction::Noop),
+            [token, ..] => Err(Error::new(format!("unknown instruction '{}'", token))
+                .at_column(column_of(line, token))),
+            [] => Err(Error::new("expected an instruction")),
+        }
+    }
+}
+
+/// The handheld device: a CPU with a single register driving a 40x6 CRT.
+pub struct Device {
+    cycle_count: usize,
+    register_x: i64,
+    signal_strength: i64,
+    screen: Grid<bool>,
+}
+
+impl Device {
+    pub fn new() -> Self {
+        Self {
+            cycle_count: 0,
+            register_x: 1,
+            signal_strength: 0,
+            screen: Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false),
+        }
+    }
+
+    pub fn execute_instruction(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::AddX(value) => self.add_to_x(*value),
+            Instruction::Noop => self.noop(),
+        }
+    }
+
+    pub fn cycle_count(&self) -> usize {
+        self.cycle_count
+    }
+
+    pub fn register_x(&self) -> i64 {
+        self.register_x
+    }
+
+    /// Sum of the signal strengths during the 20th, 60th, ... 220th cycle.
+    pub fn signal_strength(&self) -> i64 {
+        self.signal_strength
+    }
+
+    fn tick_cycle(&mut self) {
+        self.render_pixel();
+
+        self.cycle_count += 1;
+        trace!(
+            10,
+            "cycle {}: X = {}, signal strength {}",
+            self.cycle_count,
+            self.register_x,
+            i64::try_from(self.cycle_count).unwrap() * self.register_x
+        );
+        if self.cycle_count >= 20
+            && (self.cycle_count - 20).is_multiple_of(40)
+            && self.cycle_count <= 220
+        {
+            self.signal_strength += i64::try_from(self.cycle_count).unwrap() * self.register_x;
+        }
+    }
+
+    fn render_pixel(&mut self) {
+        if self.cycle_count >= SCREEN_WIDTH * SCREEN_HEIGHT {
+            return;
+        }
+
+        //The sprite is three pixels wide and only moves horizontally
+        let (current_column, _) = self.screen.position_of(self.cycle_count);
+        let current_column = i64::try_from(current_column).unwrap();
+
+        let is_current_pixel_lit =
+            current_column >= self.register_x - 1 && current_column <= self.register_x + 1;
+
+        self.screen[self.cycle_count] = is_current_pixel_lit;
+    }
+
+    fn noop(&mut self) {
+        self.tick_cycle();
+    }
+
+    fn add_to_x(&mut self, value_to_add: i64) {
+        self.tick_cycle();
+        self.tick_cycle();
+        self.register_x += value_to_add;
+    }
+
+    /// Rows of the CRT with lit pixels as `#` and dark pixels as `.`.
+    pub fn render_screen(&self) -> Vec<String> {
+        self.screen.render(|pixel| match pixel {
+            true => '#',
+            false => '.',
+        })
+    }
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Day10 {
+    instructions: Vec<Instruction>,
+}
+
+impl Day10 {
+    fn run_device(&self) -> Device {
+        let mut device = Device::new();
+        for instruction in self.instructions.iter() {
+            device.execute_instruction(instruction);
+        }
+
+        device
+    }
+}
+
+impl<'a> Solution<'a> for Day10 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        Ok(Self {
+            instructions: parse_lines(lines, Instruction::from_line)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(self.run_device().signal_strength.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(Answer::Bitmap(self.run_device().render_screen()))
+    }
+}
+
+/// Generates a program of `size` instructions, extended until it runs long
+/// enough to draw the whole screen. `addx` moves the sprite to a random column.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut instruction_count = 0;
+    let mut cycle_count = 0;
+    let mut register_x: i64 = 1;
+
+    while instruction_count < size || cycle_count < SCREEN_WIDTH * SCREEN_HEIGHT {
+        if rng.chance(40) {
+            input.push_str("noop\n");
+            cycle_count += 1;
+        } else {
+            let target_x = rng.range(0..=SCREEN_WIDTH - 1) as i64;
+            input.push_str(&format!("addx {}\n", target_x - register_x));
+            register_x = target_x;
+            cycle_count += 2;
+        }
+
+        instruction_count += 1;
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day10::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(13140));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day10::from_input(EXAMPLE).unwrap();
+        let screen = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(
+            day.part_two().unwrap(),
+            Answer::Bitmap(screen.iter().map(|row| String::from(*row)).collect())
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day10::from_input(input).unwrap();
+            let Answer::Number(signal_strength) = day.part_one().unwrap() else {
+                panic!("expected a number");
+            };
+            let Answer::Bitmap(rows) = day.part_two().unwrap() else {
+                panic!("expected a bitmap");
+            };
+
+            //The sprite always stays on the screen and the program runs long
+            //enough to draw every pixel
+            let cycles = [20, 60, 100, 140, 180, 220];
+            let max_x = SCREEN_WIDTH as i64 - 1;
+            assert!((0..=cycles.iter().sum::<i64>() * max_x).contains(&signal_strength));
+            assert_eq!(rows.len(), SCREEN_HEIGHT);
+            assert!(rows.iter().all(|row| row.len() == SCREEN_WIDTH));
+        });
+    }
+}
@@ -0,0 +1,368 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{parse_blocks, Block, Parser, Rng};
+use crate::{debug, trace};
+use std::{cmp::Ordering, collections::VecDeque};
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Addition,
+    Multiplication,
+}
+
+impl Operator {
+    fn from_token(token: &str) -> Result<Self> {
+        match token {
+            "+" => Ok(Operator::Addition),
+            "*" => Ok(Operator::Multiplication),
+            _ => Err(Error::new(format!("unexpected operator '{}'", token))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OperatorValue {
+    Literal(u64),
+    Itself,
+}
+
+impl OperatorValue {
+    fn from_token(token: &str) -> Result<Self> {
+        match token {
+            "old" => Ok(OperatorValue::Itself),
+            _ => match token.parse() {
+                Ok(value) => Ok(OperatorValue::Literal(value)),
+                Err(why) => Err(Error::new(format!("invalid operand '{}': {}", token, why))),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    operator: Operator,
+    right_hand_side: OperatorValue,
+}
+
+impl Operation {
+    /// Reads an operation like `Operation: new = old * 19`.
+    fn parse(line: &mut Parser) -> Result<Self> {
+        line.literal("Operation: new = old")?;
+
+        Ok(Self {
+            operator: line.token(Operator::from_token)?,
+            right_hand_side: line.token(OperatorValue::from_token)?,
+        })
+    }
+
+    fn execute(&self, left_hand_side: &mut u64) {
+        match (&self.operator, &self.right_hand_side) {
+            (Operator::Addition, OperatorValue::Itself) => *left_hand_side += *left_hand_side,
+            (Operator::Multiplication, OperatorValue::Itself) => *left_hand_side *= *left_hand_side,
+            (Operator::Addition, OperatorValue::Literal(value)) => *left_hand_side += *value,
+            (Operator::Multiplication, OperatorValue::Literal(value)) => *left_hand_side *= *value,
+        }
+    }
+}
+
+struct Throw {
+    item: u64,
+    receiver: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test_divisor: u64,
+    true_receiving_monkey: usize,
+    false_receiving_monkey: usize,
+    inspected_item_count: usize,
+}
+
+impl Monkey {
+    /// Reads the notes of one monkey from its block of lines.
+    fn from_block(block: &mut Block) -> Result<Self> {
+        block.line(|line| {
+            line.literal("Monkey")?;
+            line.unsigned::<usize>()?;
+            line.literal(":")
+        })?;
+        let items = block.line(|line| {
+            line.literal("Starting items:")?;
+            line.separated(",", Parser::unsigned)
+        })?;
+        let operation = block.line(Operation::parse)?;
+        let test_divisor = block.line(|line| {
+            line.literal("Test: divisible by")?;
+            line.whitespace();
+            let column = line.column();
+            match line.unsigned()? {
+                0 => Err(Error::new("test divisor must not be 0").at_column(column)),
+                test_divisor => Ok(test_divisor),
+            }
+        })?;
+        let true_receiving_monkey = block.line(|line| {
+            line.literal("If true: throw to monkey")?;
+            line.unsigned()
+        })?;
+        let false_receiving_monkey = block.line(|line| {
+            line.literal("If false: throw to monkey")?;
+            line.unsigned()
+        })?;
+
+        Ok(Self {
+            items: VecDeque::from(items),
+            operation,
+            test_divisor,
+            true_receiving_monkey,
+            false_receiving_monkey,
+            inspected_item_count: 0,
+        })
+    }
+
+    //Look at chinese remainder theorem
+    fn turn_with_relief(&mut self, relief_factor: u64, magic_divider: u64) -> Vec<Throw> {
+        let mut result = Vec::with_capacity(self.items.len());
+        while let Some(mut current_item) = self.items.pop_front() {
+            self.operation.execute(&mut current_item);
+            current_item %= magic_divider;
+
+            if let Some(relieved_item) = current_item.checked_div(relief_factor) {
+                current_item = relieved_item;
+            }
+
+            let throwing_to_monkey = match current_item % self.test_divisor {
+                0 => self.true_receiving_monkey,
+                _ => self.false_receiving_monkey,
+            };
+
+            result.push(Throw {
+                item: current_item,
+                receiver: throwing_to_monkey,
+            });
+            self.inspected_item_count += 1;
+        }
+
+        result
+    }
+
+    fn compare_monkey(left: &Monkey, right: &Monkey) -> Ordering {
+        right.inspected_item_count.cmp(&left.inspected_item_count)
+    }
+}
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+    fn monkey_business(&self, round_count: usize, relief_factor: u64) -> Result<usize> {
+        if self.monkeys.len() < 2 {
+            return Err(Error::new("monkey business needs at least two monkeys"));
+        }
+
+        let mut monkeys = self.monkeys.clone();
+
+        play_out_turns(&mut monkeys, round_count, relief_factor);
+        debug!(
+            11,
+            "inspected items after {} rounds: {:?}",
+            round_count,
+            monkeys
+                .iter()
+                .map(|monkey| monkey.inspected_item_count)
+                .collect::<Vec<_>>()
+        );
+        monkeys.sort_by(Monkey::compare_monkey);
+
+        Ok(monkeys[0].inspected_item_count * monkeys[1].inspected_item_count)
+    }
+}
+
+impl<'a> Solution<'a> for Day11 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let monkeys = parse_blocks(lines, Monkey::from_block)?;
+
+        for (index, monkey) in monkeys.iter().enumerate() {
+            for receiver in [monkey.true_receiving_monkey, monkey.false_receiving_monkey] {
+                if receiver >= monkeys.len() {
+                    return Err(Error::new(format!(
+                        "monkey {} throws to unknown monkey {}",
+                        index, receiver
+                    )));
+                }
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(self.monkey_business(20, 3)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(self.monkey_business(10000, 0)?.into())
+    }
+}
+
+fn play_out_turns(monkeys: &mut [Monkey], round_count: usize, relief_factor: u64) {
+    let magic_divider: u64 = monkeys.iter().map(|monkey| monkey.test_divisor).product();
+
+    for _ in 0..round_count {
+        play_out_single_turn(monkeys, relief_factor, magic_divider);
+    }
+}
+
+fn play_out_single_turn(monkeys: &mut [Monkey], relief_factor: u64, magic_divider: u64) {
+    for i in 0..monkeys.len() {
+        let throws = monkeys[i].turn_with_relief(relief_factor, magic_divider);
+        for throw in throws {
+            trace!(
+                11,
+                "monkey {} throws {} to monkey {}",
+                i,
+                throw.item,
+                throw.receiver
+            );
+            monkeys[throw.receiver].items.push_back(throw.item);
+        }
+    }
+}
+
+/// Test divisors of generated monkeys, distinct primes keep the product of all
+/// divisors and so the worry levels of star two small.
+const GENERATED_DIVISORS: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+/// Generates the notes of `size` monkeys, at least 2 and at most 8.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let monkey_count = size.clamp(2, GENERATED_DIVISORS.len());
+    let mut divisors = GENERATED_DIVISORS.to_vec();
+    rng.shuffle(&mut divisors);
+
+    let mut input = String::new();
+    for (monkey, divisor) in divisors.iter().take(monkey_count).enumerate() {
+        let mut items = Vec::new();
+        for _ in 0..rng.range(1..=8) {
+            items.push(rng.range(50..=99).to_string());
+        }
+
+        let operation = match rng.range(0..=4) {
+            0 => String::from("old * old"),
+            1 | 2 => format!("old * {}", rng.range(2..=19)),
+            _ => format!("old + {}", rng.range(1..=8)),
+        };
+
+        let true_receiver = generate_receiver(rng, monkey, monkey_count);
+        let mut false_receiver = generate_receiver(rng, monkey, monkey_count);
+        while monkey_count > 2 && false_receiver == true_receiver {
+            false_receiver = generate_receiver(rng, monkey, monkey_count);
+        }
+
+        input.push_str(&format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n\n",
+            monkey,
+            items.join(", "),
+            operation,
+            divisor,
+            true_receiver,
+            false_receiver
+        ));
+    }
+
+    input
+}
+
+/// A random monkey other than `monkey`.
+fn generate_receiver(rng: &mut Rng, monkey: usize, monkey_count: usize) -> usize {
+    let receiver = rng.range(0..=monkey_count - 2);
+    if receiver >= monkey {
+        return receiver + 1;
+    }
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day11::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(10605));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day11::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(2713310158));
+    }
+
+    #[test]
+    fn tolerates_spacing() {
+        let input = EXAMPLE
+            .replace(": ", ":   ")
+            .replace(", ", " ,")
+            .replace("  ", "\t");
+        let day = Day11::from_input(&input).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(10605));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day11::from_input(input).unwrap();
+            let items = input
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix("Starting items: "))
+                .map(|items| items.split(", ").count() as i64)
+                .sum::<i64>();
+
+            //Every item is inspected at least once a round, so the busiest of
+            //the 8 monkeys does an eighth of that, and every monkey starts with
+            //items, so the second busiest inspects at least one
+            for (answer, rounds) in [
+                (day.part_one().unwrap(), 20),
+                (day.part_two().unwrap(), 10_000),
+            ] {
+                let Answer::Number(monkey_business) = answer else {
+                    panic!("expected a number");
+                };
+                assert!(monkey_business >= rounds * items / 8);
+            }
+        });
+    }
+}
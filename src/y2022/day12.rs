@@ -0,0 +1,395 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::util::{Direction, Grid, Point, Rng};
+
+/// The heights of a map from `a` (0) to `z` (25) with the start `S` and the
+/// end `E`.
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl HeightMap {
+    pub fn parse_height_map(lines: &[&str]) -> Result<Self> {
+        if lines.is_empty() || lines[0].is_empty() {
+            return Err(Error::new("Empty Map"));
+        }
+
+        let heights = Grid::parse(lines, HeightMap::parse_height_marker)?;
+
+        //All lines have the same length now, so the flat index of a marker is
+        //its position in the concatenated lines
+        let find_marker = |marker: char| {
+            lines
+                .iter()
+                .flat_map(|line| line.chars())
+                .position(|height_marker| height_marker == marker)
+                .map(|index| heights.point_of(index))
+        };
+        let start = find_marker('S').ok_or_else(|| Error::new("Map has no start 'S'"))?;
+        let end = find_marker('E').ok_or_else(|| Error::new("Map has no end 'E'"))?;
+
+        Ok(Self {
+            heights,
+            start,
+            end,
+        })
+    }
+
+    fn parse_height_marker(height_marker: char) -> Result<u8> {
+        match height_marker {
+            'S' => Ok(0),
+            'E' => Ok(25),
+            'a'..='z' => Ok(height_marker as u8 - 97),
+            _ => Err(Error::new(format!(
+                "unexpected height marker '{}'",
+                height_marker
+            ))),
+        }
+    }
+
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// All squares of the lowest height `a`.
+    pub fn find_all_possible_starting_positions(&self) -> Vec<Point> {
+        let mut result = Vec::new();
+
+        for (index, height) in self.heights.cells().iter().enumerate() {
+            if *height == 0 {
+                result.push(self.heights.point_of(index));
+            }
+        }
+
+        result
+    }
+
+    /// The squares on a shortest path from `start` to the end, without the
+    /// start itself, or `None` if the end cannot be reached.
+    pub fn find_shortest_path(&self, start: Point) -> Option<Vec<Point>> {
+        //The search itself works on flat indices into the grid
+        let start_index = self.heights.index_of_point(start)?;
+        let end_index = self.heights.index_of_point(self.end)?;
+
+        let nodes = DijkstraNode::build_dijkstra_tree(self, start_index);
+        let path = DijkstraNode::try_to_get_path_from_start_to_end(&nodes, start_index, end_index)?;
+
+        Some(
+            path.into_iter()
+                .map(|index| self.heights.point_of(index))
+                .collect(),
+        )
+    }
+
+    /// Draws `path` from `start` like the puzzle text does: every square of
+    /// the path points to the next one with `>`, `<`, `^` or `v`, the end is
+    /// `E` and all other squares are `.`.
+    pub fn draw_path(&self, start: Point, path: &[Point]) -> Vec<String> {
+        let mut drawing = Grid::new(self.heights.width(), self.heights.height(), '.');
+
+        let mut current = start;
+        for next in path.iter() {
+            let arrow = match Direction::ALL
+                .iter()
+                .find(|direction| direction.step() == *next - current)
+            {
+                Some(Direction::Up) => '^',
+                Some(Direction::Down) => 'v',
+                Some(Direction::Left) => '<',
+                Some(Direction::Right) => '>',
+                None => '?',
+            };
+            drawing[current] = arrow;
+            current = *next;
+        }
+        drawing[self.end] = 'E';
+
+        drawing.render(|square| *square)
+    }
+
+    fn find_neighbours(&self, current_position: usize) -> Vec<usize> {
+        self.heights
+            .neighbours(current_position)
+            .filter(|neighbour_position| self.is_climable(current_position, *neighbour_position))
+            .collect()
+    }
+
+    fn is_climable(&self, current_position: usize, target_position: usize) -> bool {
+        let current_height = self.heights[current_position];
+        let target_height = self.heights[target_position];
+
+        current_height > target_height || target_height - current_height < 2
+    }
+}
+
+#[derive(Debug)]
+struct DijkstraNode {
+    cost: u64,
+    previous: Option<usize>,
+}
+
+impl DijkstraNode {
+    fn try_to_get_path_from_start_to_end(
+        nodes: &[DijkstraNode],
+        start: usize,
+        end: usize,
+    ) -> Option<Vec<usize>> {
+        nodes[end].previous?;
+
+        let mut result = Vec::new();
+
+        let mut current_position = end;
+        while current_position != start {
+            result.push(current_position);
+            current_position = nodes[current_position].previous.unwrap();
+        }
+        result.reverse();
+
+        Some(result)
+    }
+
+    fn build_dijkstra_tree(height_map: &HeightMap, start_index: usize) -> Vec<DijkstraNode> {
+        let mut nodes = Self::from_height_map(height_map, start_index);
+        let mut unvisited_node_positions =
+            Self::init_unvisited_node_positions(height_map.heights.cells().len());
+
+        while !unvisited_node_positions.is_empty() {
+            let current_node_position =
+                Self::dequeue_cheapest_unvisited_node(&nodes, &mut unvisited_node_positions);
+            let current_node = &nodes[current_node_position];
+
+            //Only happens if no node exists anymore that is connected somehow with the start
+            if current_node.cost == u64::MAX {
+                break;
+            }
+
+            trace!(
+                12,
+                "settled {} at cost {}",
+                height_map.heights.point_of(current_node_position),
+                current_node.cost
+            );
+
+            //every neighbour is always one step away
+            let cost_to_neighbours = current_node.cost + 1;
+
+            let neighbour_positions = height_map.find_neighbours(current_node_position);
+            for neighbour_position in neighbour_positions {
+                let neighbour = &mut nodes[neighbour_position];
+                neighbour.update(cost_to_neighbours, current_node_position);
+            }
+        }
+
+        nodes
+    }
+
+    fn from_height_map(height_map: &HeightMap, start_index: usize) -> Vec<Self> {
+        let mut result = Vec::with_capacity(height_map.heights.cells().len());
+        for _ in 0..height_map.heights.cells().len() {
+            result.push(Self {
+                cost: u64::MAX,
+                previous: None,
+            });
+        }
+        result[start_index].cost = 0;
+
+        result
+    }
+
+    fn init_unvisited_node_positions(node_count: usize) -> Vec<usize> {
+        let mut result = Vec::with_capacity(node_count);
+
+        for i in 0..node_count {
+            result.push(i);
+        }
+
+        result
+    }
+
+    fn dequeue_cheapest_unvisited_node(
+        nodes: &[DijkstraNode],
+        unvisited_nodes_positions: &mut Vec<usize>,
+    ) -> usize {
+        let mut min_cost = u64::MAX;
+        let mut current_cheapest_node_position_in_q = 0;
+
+        for (index, node_position) in unvisited_nodes_positions.iter().enumerate() {
+            let node = &nodes[*node_position];
+            if node.cost <= min_cost {
+                min_cost = node.cost;
+                current_cheapest_node_position_in_q = index;
+            }
+        }
+
+        unvisited_nodes_positions.remove(current_cheapest_node_position_in_q)
+    }
+
+    fn update(&mut self, cost: u64, previous: usize) {
+        if cost < self.cost {
+            self.cost = cost;
+            self.previous = Some(previous);
+        }
+    }
+}
+
+pub struct Day12 {
+    height_map: HeightMap,
+}
+
+impl<'a> Solution<'a> for Day12 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        Ok(Self {
+            height_map: HeightMap::parse_height_map(lines)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        match self.height_map.find_shortest_path(self.height_map.start) {
+            Some(route) => Ok(route.len().into()),
+            None => Err(Error::new("no path from the start to the end")),
+        }
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        let starting_positions = self.height_map.find_all_possible_starting_positions();
+        let mut routes: Vec<Vec<Point>> = Vec::new();
+
+        for starting_position in starting_positions.iter() {
+            if let Some(route) = self.height_map.find_shortest_path(*starting_position) {
+                routes.push(route);
+            }
+        }
+
+        match routes.iter().map(|route| route.len()).min() {
+            Some(length) => Ok(length.into()),
+            None => Err(Error::new("no path from any lowest square to the end")),
+        }
+    }
+
+    fn render(&self) -> Option<Vec<String>> {
+        let start = self.height_map.start;
+        let path = self.height_map.find_shortest_path(start)?;
+
+        Some(self.height_map.draw_path(start, &path))
+    }
+}
+
+/// Generates a height map `size` columns wide, but at least 26 so a path can
+/// climb from `a` to `z`, and half as high. A path from `S` on the left edge to
+/// `E` on the right edge climbs at most one step at a time, the other squares
+/// are random.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = width / 2;
+    let mut rows: Vec<Vec<u8>> = Vec::with_capacity(height);
+    for _ in 0..height {
+        let mut row = Vec::with_capacity(width);
+        for _ in 0..width {
+            row.push(b'a' + rng.range(0..=25) as u8);
+        }
+        rows.push(row);
+    }
+
+    //Walk through every column from left to right, moving up or down to a
+    //random row in each of them
+    let mut path = Vec::new();
+    let mut y = rng.range(0..=height - 1);
+    for x in 0..width {
+        let target_y = rng.range(0..=height - 1);
+        path.push((x, y));
+        while y != target_y {
+            y = if y < target_y { y + 1 } else { y - 1 };
+            path.push((x, y));
+        }
+    }
+
+    for (step, (x, y)) in path.iter().enumerate() {
+        rows[*y][*x] = b'a' + (25 * step / (path.len() - 1)) as u8;
+    }
+    let (start_x, start_y) = path[0];
+    let (end_x, end_y) = path[path.len() - 1];
+    rows[start_y][start_x] = b'S';
+    rows[end_y][end_x] = b'E';
+
+    let mut input = String::with_capacity((width + 1) * height + 1);
+    for row in rows.iter() {
+        input.extend(row.iter().map(|height| char::from(*height)));
+        input.push('\n');
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day12::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(31));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day12::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(29));
+    }
+
+    #[test]
+    fn example_render() {
+        let drawing = Day12::from_input(EXAMPLE).unwrap().render().unwrap();
+
+        //Ties between shortest paths may pick other squares than the puzzle text
+        assert_eq!(drawing.len(), 5);
+        assert_eq!(&drawing[2][5..6], "E");
+        assert_eq!(drawing[0].chars().next(), Some('v'));
+        let arrow_count: usize = drawing
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .filter(|square| "<>^v".contains(*square))
+                    .count()
+            })
+            .sum();
+        assert_eq!(arrow_count, 31);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(3, 26, generate, |input| {
+            let day = Day12::from_input(input).unwrap();
+            let (Answer::Number(from_start), Answer::Number(from_lowest)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //Climbing from a to z takes at least 25 steps, and the start is one
+            //of the lowest squares
+            assert!((25..=from_start).contains(&from_lowest));
+        });
+    }
+}
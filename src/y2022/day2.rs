@@ -0,0 +1,168 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::Rng;
+
+#[derive(Clone)]
+enum RpsChoice {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl RpsChoice {
+    fn convert_from(from: &str) -> Result<Self> {
+        match from {
+            "A" => Ok(RpsChoice::Rock),
+            "B" => Ok(RpsChoice::Paper),
+            "C" => Ok(RpsChoice::Scissor),
+            "X" => Ok(RpsChoice::Rock),
+            "Y" => Ok(RpsChoice::Paper),
+            "Z" => Ok(RpsChoice::Scissor),
+            _ => Err(Error::new(format!("unexpected choice '{}'", from))),
+        }
+    }
+
+    fn convert_from_opponent_and_result(opponent: &RpsChoice, result: &str) -> Result<Self> {
+        match (opponent, result) {
+            (RpsChoice::Rock, "X") => Ok(RpsChoice::Scissor),
+            (RpsChoice::Rock, "Z") => Ok(RpsChoice::Paper),
+            (RpsChoice::Paper, "X") => Ok(RpsChoice::Rock),
+            (RpsChoice::Paper, "Z") => Ok(RpsChoice::Scissor),
+            (RpsChoice::Scissor, "X") => Ok(RpsChoice::Paper),
+            (RpsChoice::Scissor, "Z") => Ok(RpsChoice::Rock),
+            (_, "Y") => Ok(opponent.clone()),
+            (_, _) => Err(Error::new(format!("unexpected result '{}'", result))),
+        }
+    }
+
+    fn points_for_choice(&self) -> u32 {
+        match self {
+            RpsChoice::Rock => 1,
+            RpsChoice::Paper => 2,
+            RpsChoice::Scissor => 3,
+        }
+    }
+
+    fn points_for_outcome(&self, opponent: &RpsChoice) -> u32 {
+        match (self, opponent) {
+            (RpsChoice::Rock, RpsChoice::Paper) => 0,
+            (RpsChoice::Rock, RpsChoice::Scissor) => 6,
+            (RpsChoice::Paper, RpsChoice::Scissor) => 0,
+            (RpsChoice::Paper, RpsChoice::Rock) => 6,
+            (RpsChoice::Scissor, RpsChoice::Rock) => 0,
+            (RpsChoice::Scissor, RpsChoice::Paper) => 6,
+            (_, _) => 3,
+        }
+    }
+}
+
+pub struct Day2<'a> {
+    rounds: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Day2<'a> {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let mut rounds = Vec::with_capacity(lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                return Err(Error::new("unexpected empty line").at_line(index + 1, line));
+            }
+
+            check_round(line).map_err(|why| why.at_line(index + 1, line))?;
+            rounds.push(*line);
+        }
+
+        Ok(Self { rounds })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(play_rounds(&self.rounds, |_, choice| RpsChoice::convert_from(choice))?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(play_rounds(&self.rounds, RpsChoice::convert_from_opponent_and_result)?.into())
+    }
+}
+
+fn check_round(line: &str) -> Result<()> {
+    let (Some((opponent, choice)), 3) = (line.split_once(' '), line.chars().count()) else {
+        return Err(Error::new("expected a round like 'A X'"));
+    };
+
+    RpsChoice::convert_from(opponent).map_err(|why| why.at_column(1))?;
+    RpsChoice::convert_from(choice).map_err(|why| why.at_column(3))?;
+
+    Ok(())
+}
+
+fn play_rounds(
+    rounds: &[&str],
+    pick_choice: fn(&RpsChoice, &str) -> Result<RpsChoice>,
+) -> Result<u32> {
+    let mut score: u32 = 0;
+
+    for round in rounds {
+        let opponent = RpsChoice::convert_from(&round[0..1])?;
+        let myself = pick_choice(&opponent, &round[2..3])?;
+        score += myself.points_for_choice();
+        score += myself.points_for_outcome(&opponent);
+    }
+
+    Ok(score)
+}
+
+/// Generates a strategy guide of `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::with_capacity(size * 4 + 1);
+
+    for _ in 0..size.max(1) {
+        input.push(*rng.pick(&['A', 'B', 'C']));
+        input.push(' ');
+        input.push(*rng.pick(&['X', 'Y', 'Z']));
+        input.push('\n');
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day2::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(15));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day2::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(12));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day2::from_input(input).unwrap();
+
+            //Each of the 50 rounds scores between 1 and 9 points
+            for answer in [day.part_one().unwrap(), day.part_two().unwrap()] {
+                let Answer::Number(score) = answer else {
+                    panic!("expected a number");
+                };
+                assert!((50..=50 * 9).contains(&score));
+            }
+        });
+    }
+}
@@ -0,0 +1,203 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::Rng;
+
+pub struct Day3 {
+    rucksacks: Vec<Vec<u8>>,
+}
+
+impl<'a> Solution<'a> for Day3 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let mut rucksacks = Vec::with_capacity(lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                return Err(Error::new("unexpected empty line").at_line(index + 1, line));
+            }
+
+            let mut priorities = Vec::with_capacity(line.len());
+            for (column, letter_code) in line.bytes().enumerate() {
+                let letter_priority = convert_letter_to_priority(letter_code)
+                    .map_err(|why| why.at_column(column + 1).at_line(index + 1, line))?;
+                priorities.push(letter_priority);
+            }
+
+            rucksacks.push(priorities);
+        }
+
+        Ok(Self { rucksacks })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        let mut sum_of_priority_collisions: u64 = 0;
+
+        for rucksack in self.rucksacks.iter() {
+            let mut bloom_filter: u64 = 0;
+            let middle = rucksack.len() / 2;
+            set_bloom_filter(&rucksack[0..middle], &mut bloom_filter);
+            sum_of_priority_collisions += u64::from(check_for_collision(
+                &rucksack[middle..rucksack.len()],
+                &bloom_filter,
+            ));
+        }
+
+        Ok(sum_of_priority_collisions.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        let mut sum_of_badge_priorities: u64 = 0;
+
+        for group in self.rucksacks.chunks(3) {
+            if group.len() != 3 {
+                return Err(Error::new(format!(
+                    "expected groups of three rucksacks but the last group has {}",
+                    group.len()
+                )));
+            }
+
+            let mut first_bloom_filter: u64 = 0;
+            let mut second_bloom_filter: u64 = 0;
+            let mut third_bloom_filter: u64 = 0;
+
+            set_bloom_filter(&group[0], &mut first_bloom_filter);
+            set_bloom_filter(&group[1], &mut second_bloom_filter);
+            set_bloom_filter(&group[2], &mut third_bloom_filter);
+
+            let intersection = first_bloom_filter & second_bloom_filter & third_bloom_filter;
+            sum_of_badge_priorities += u64::from(search_for_set_bit(intersection));
+        }
+
+        Ok(sum_of_badge_priorities.into())
+    }
+}
+
+fn set_bloom_filter(priorities: &[u8], bloom_filter: &mut u64) {
+    for letter_priority in priorities {
+        *bloom_filter |= 1 << (letter_priority - 1);
+    }
+}
+
+fn search_for_set_bit(intersection: u64) -> u8 {
+    let mut intersection = intersection;
+    for i in 0..52 {
+        if intersection & 1 == 1 {
+            return i + 1;
+        }
+
+        intersection >>= 1;
+    }
+
+    0
+}
+
+fn check_for_collision(priorities: &[u8], bloom_filter: &u64) -> u8 {
+    for letter_priority in priorities {
+        if ((bloom_filter >> (letter_priority - 1)) & 1) == 1 {
+            return *letter_priority;
+        }
+    }
+
+    0
+}
+
+fn convert_letter_to_priority(letter_code: u8) -> Result<u8> {
+    if (97..=122).contains(&letter_code) {
+        return Ok(letter_code - 96);
+    }
+
+    if (65..=90).contains(&letter_code) {
+        return Ok(letter_code - 64 + 26);
+    }
+
+    Err(Error::new(format!(
+        "unexpected item '{}'",
+        char::from(letter_code).escape_default()
+    )))
+}
+
+/// Generates `size` rucksacks, rounded up to whole groups of three. Both
+/// compartments of a rucksack share exactly one item type and the rucksacks of
+/// a group share exactly one badge.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        let mut item_types: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+        rng.shuffle(&mut item_types);
+        let badge = item_types[0];
+
+        //Every rucksack of the group gets its own 17 item types, the first one
+        //is in both compartments and the others only in one of them
+        for own_item_types in item_types[1..].chunks(17) {
+            let shared = own_item_types[0];
+            let mut left = vec![shared];
+            let mut right = vec![shared];
+            for _ in 1..rng.range(2..=12) {
+                left.push(*rng.pick(&own_item_types[1..9]));
+                right.push(*rng.pick(&own_item_types[9..]));
+            }
+
+            match rng.chance(50) {
+                true => left[1] = badge,
+                false => right[1] = badge,
+            }
+
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            input.extend(
+                left.iter()
+                    .chain(right.iter())
+                    .map(|item| char::from(*item)),
+            );
+            input.push('\n');
+        }
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day3::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(157));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day3::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(70));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day3::from_input(input).unwrap();
+            let (Answer::Number(rucksacks), Answer::Number(badges)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //51 rucksacks and 17 groups, each with exactly one shared item of
+            //priority 1 to 52
+            assert!((51..=51 * 52).contains(&rucksacks));
+            assert!((17..=17 * 52).contains(&badges));
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{parse_line, Parser, Rng};
+
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn parse(line: &mut Parser) -> Result<Self> {
+        let start = line.unsigned()?;
+        line.literal("-")?;
+        let end = line.unsigned()?;
+
+        Ok(Self { start, end })
+    }
+
+    fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.start && other.start <= self.end
+            || other.start <= self.start && self.start <= other.end
+    }
+}
+
+pub struct Day4 {
+    pairs: Vec<(Range, Range)>,
+}
+
+impl<'a> Solution<'a> for Day4 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let mut pairs = Vec::with_capacity(lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                return Err(Error::new("unexpected empty line").at_line(index + 1, line));
+            }
+
+            let pair =
+                parse_line(line, parse_range_pair).map_err(|why| why.at_line(index + 1, line))?;
+            pairs.push(pair);
+        }
+
+        Ok(Self { pairs })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(self
+            .pairs
+            .iter()
+            .filter(|(elve_a, elve_b)| elve_a.contains(elve_b) || elve_b.contains(elve_a))
+            .count()
+            .into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(self
+            .pairs
+            .iter()
+            .filter(|(elve_a, elve_b)| elve_a.overlaps(elve_b))
+            .count()
+            .into())
+    }
+}
+
+/// Reads a section pair like `2-4,6-8`.
+fn parse_range_pair(line: &mut Parser) -> Result<(Range, Range)> {
+    let first = Range::parse(line)?;
+    line.literal(",")?;
+    let second = Range::parse(line)?;
+
+    Ok((first, second))
+}
+
+/// Generates `size` pairs of section assignments between 1 and 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let (first_start, first_end) = generate_sections(rng);
+        let (second_start, second_end) = generate_sections(rng);
+        input.push_str(&format!(
+            "{}-{},{}-{}\n",
+            first_start, first_end, second_start, second_end
+        ));
+    }
+    input.push('\n');
+
+    input
+}
+
+fn generate_sections(rng: &mut Rng) -> (usize, usize) {
+    let start = rng.range(1..=99);
+    (start, rng.range(start..=99))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day4::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(2));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day4::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(4));
+    }
+
+    #[test]
+    fn tolerates_spacing() {
+        let day = Day4::from_input(&EXAMPLE.replace(',', " , ").replace('-', " - ")).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(2));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day4::from_input(input).unwrap();
+            let (Answer::Number(containing), Answer::Number(overlapping)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //A pair that contains the other one also overlaps it
+            assert!(containing <= overlapping && overlapping <= 50);
+        });
+    }
+}
@@ -0,0 +1,319 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use crate::util::{blocks, Block, Parser, Rng};
+
+#[derive(Debug)]
+struct Move {
+    from: usize,
+    to: usize,
+    amount: usize,
+}
+
+impl Move {
+    /// Reads a move like `move 1 from 2 to 3`.
+    fn parse(line: &mut Parser) -> Result<Self> {
+        line.literal("move")?;
+        let amount = line.unsigned()?;
+        line.literal("from")?;
+        let from = line.unsigned()?;
+        line.literal("to")?;
+        let to = line.unsigned()?;
+
+        Ok(Self { from, to, amount })
+    }
+
+    fn check_stacks(&self, stack_count: usize) -> Result<()> {
+        for stack in [self.from, self.to] {
+            if stack == 0 || stack > stack_count {
+                return Err(Error::new(format!(
+                    "unknown stack {}, expected 1 to {}",
+                    stack, stack_count
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day5 {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl<'a> Solution<'a> for Day5 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        //The drawing of the stacks and the moves are separated by an empty line
+        let mut blocks = blocks(lines);
+        let drawing = match blocks.next() {
+            Some((_, drawing)) => drawing,
+            None => return Err(Error::new("expected a drawing of the stacks")),
+        };
+        let (first_move_index, move_lines) = match blocks.next() {
+            Some(block) => block,
+            None => {
+                return Err(Error::new(
+                    "expected an empty line between the stacks and the moves",
+                ))
+            }
+        };
+        if let Some((index, unexpected_lines)) = blocks.next() {
+            return Err(Error::new("unexpected lines after the moves")
+                .at_line(index + 1, unexpected_lines[0]));
+        }
+
+        let stack_count = read_in_stack_count(drawing)?;
+        let stacks = init_stacks(stack_count, &drawing[..drawing.len() - 1]);
+        let moves = Block::new(move_lines, first_move_index).remaining_lines(Move::parse)?;
+
+        for (offset, stack_move) in moves.iter().enumerate() {
+            let index = first_move_index + offset;
+            stack_move
+                .check_stacks(stack_count)
+                .map_err(|why| why.at_line(index + 1, lines[index]))?;
+        }
+
+        Ok(Self { stacks, moves })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        let mut stacks = self.stacks.clone();
+
+        //Execute moves
+        for stack_move in self.moves.iter() {
+            for _i in 0..stack_move.amount {
+                let to_move = pop_crate(&mut stacks, stack_move.from)?;
+                stacks[stack_move.to - 1].push(to_move);
+            }
+            trace_move(1, stack_move, &stacks);
+        }
+
+        Ok(build_result(&stacks).into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        let mut stacks = self.stacks.clone();
+
+        //Execute moves
+        for stack_move in self.moves.iter() {
+            let mut moved_elements = Vec::with_capacity(stack_move.amount);
+            for _i in 0..stack_move.amount {
+                moved_elements.push(pop_crate(&mut stacks, stack_move.from)?);
+            }
+
+            while let Some(moved_element) = moved_elements.pop() {
+                stacks[stack_move.to - 1].push(moved_element);
+            }
+            trace_move(2, stack_move, &stacks);
+        }
+
+        Ok(build_result(&stacks).into())
+    }
+}
+
+fn trace_move(star: u8, stack_move: &Move, stacks: &[Vec<char>]) {
+    trace!(
+        5,
+        "star {}: moved {} from {} to {}, top crates {}",
+        star,
+        stack_move.amount,
+        stack_move.from,
+        stack_move.to,
+        build_result(stacks)
+    );
+}
+
+fn pop_crate(stacks: &mut [Vec<char>], stack: usize) -> Result<char> {
+    match stacks[stack - 1].pop() {
+        Some(value) => Ok(value),
+        None => Err(Error::new(format!(
+            "tried to move a crate from empty stack {}",
+            stack
+        ))),
+    }
+}
+
+/// Reads the number of stacks from the last line of the drawing.
+fn read_in_stack_count(drawing: &[&str]) -> Result<usize> {
+    let line_number = drawing.len();
+    let line = drawing[line_number - 1];
+
+    let last_number = line.split_whitespace().last().unwrap_or("");
+    match last_number.parse::<usize>() {
+        Ok(value) => Ok(value),
+        Err(_) => Err(Error::new(format!(
+            "expected the number of the last stack but found '{}'",
+            last_number
+        ))
+        .at_line(line_number, line)),
+    }
+}
+
+fn init_stacks(count: usize, crate_lines: &[&str]) -> Vec<Vec<char>> {
+    let mut stacks = vec![];
+    for _i in 0..count {
+        stacks.push(Vec::new());
+    }
+
+    //The bottom crates are in the last line
+    for line in crate_lines.iter().rev() {
+        read_stack_line(&mut stacks, line, count);
+    }
+
+    stacks
+}
+
+fn read_stack_line(stacks: &mut [Vec<char>], line: &str, stack_count: usize) {
+    let mut stack_index = 0;
+    let mut letter_index = 1;
+    while stack_index < stack_count {
+        //Lines may be missing trailing spaces after the last crate
+        if let Some(letter) = line
+            .get(letter_index..)
+            .and_then(|rest| rest.chars().next())
+        {
+            if letter != ' ' {
+                stacks[stack_index].push(letter);
+            }
+        }
+
+        stack_index += 1;
+        letter_index += 4;
+    }
+}
+
+fn build_result(stacks: &[Vec<char>]) -> String {
+    let mut result = String::from("");
+    for stack in stacks {
+        if let Some(top) = stack.last() {
+            result.push(*top);
+        }
+    }
+
+    result
+}
+
+/// Generates a drawing of two to nine stacks and `size` moves, which never take
+/// more crates from a stack than it holds at that point.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stack_count = rng.range(2..=9);
+    let mut stacks: Vec<Vec<char>> = Vec::with_capacity(stack_count);
+    for _ in 0..stack_count {
+        let mut stack = Vec::new();
+        for _ in 0..rng.range(0..=8) {
+            stack.push(char::from(*rng.pick(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ")));
+        }
+        stacks.push(stack);
+    }
+    if stacks[0].is_empty() {
+        stacks[0].push('A');
+    }
+
+    let mut input = String::new();
+    let max_height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    for level in (0..max_height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(letter) => format!("[{}]", letter),
+                None => String::from("   "),
+            })
+            .collect();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+
+    let numbers: Vec<String> = (1..=stack_count)
+        .map(|number| format!(" {} ", number))
+        .collect();
+    input.push_str(&numbers.join(" "));
+    input.push_str("\n\n");
+
+    //Only the heights matter to keep the moves possible
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+    for _ in 0..size.max(1) {
+        let filled_stacks: Vec<usize> = (0..stack_count)
+            .filter(|stack| heights[*stack] > 0)
+            .collect();
+        let from = *rng.pick(&filled_stacks);
+        let mut to = rng.range(0..=stack_count - 2);
+        if to >= from {
+            to += 1;
+        }
+        let amount = rng.range(1..=heights[from]);
+
+        heights[from] -= amount;
+        heights[to] += amount;
+        input.push_str(&format!(
+            "move {} from {} to {}\n",
+            amount,
+            from + 1,
+            to + 1
+        ));
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2\n",
+        "\n",
+    );
+
+    #[test]
+    fn example_star_1() {
+        let day = Day5::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Text(String::from("CMZ")));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day5::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Text(String::from("MCD")));
+    }
+
+    #[test]
+    fn tolerates_spacing_in_moves() {
+        let day =
+            Day5::from_input(&EXAMPLE.replace("move ", "move  ").replace(" to", "\tto")).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Text(String::from("MCD")));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day5::from_input(input).unwrap();
+            let stack_count = input
+                .lines()
+                .find(|line| line.starts_with(" 1 "))
+                .unwrap()
+                .split_whitespace()
+                .count();
+            let (Answer::Text(one_at_a_time), Answer::Text(whole_piles)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected texts");
+            };
+
+            //Both cranes move the same number of crates between the same
+            //stacks, so the same stacks end up empty
+            assert!((1..=stack_count).contains(&one_at_a_time.len()));
+            assert_eq!(one_at_a_time.len(), whole_piles.len());
+        });
+    }
+}
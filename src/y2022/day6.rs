@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::Rng;
+
+pub struct Day6<'a> {
+    signal: &'a str,
+}
+
+impl<'a> Solution<'a> for Day6<'a> {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let signal = match lines.first() {
+            Some(line) => *line,
+            None => return Err(Error::new("expected a line with the signal")),
+        };
+
+        if let Some(column) = signal.find(|letter: char| !letter.is_ascii()) {
+            return Err(Error::new("signal contains a non ASCII character")
+                .at_column(column + 1)
+                .at_line(1, signal));
+        }
+
+        Ok(Self { signal })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(detect_marker(self.signal, 4)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(detect_marker(self.signal, 14)?.into())
+    }
+}
+
+fn detect_marker(line: &str, marker_length: usize) -> Result<usize> {
+    if line.len() < marker_length {
+        return Err(Error::new(format!(
+            "Line needs to be {} chars long, but was {}",
+            marker_length,
+            line.len()
+        )));
+    }
+
+    let mut letter_counter: HashMap<&str, usize> = HashMap::with_capacity(marker_length);
+    for i in 0..(marker_length - 1) {
+        let letter = &line[i..i + 1];
+        raise_letter_count(&mut letter_counter, letter);
+    }
+
+    for i in (marker_length - 1)..line.len() {
+        let head = &line[i..i + 1];
+        raise_letter_count(&mut letter_counter, head);
+
+        if is_only_unique_letters(&letter_counter) {
+            return Ok(i + 1);
+        }
+
+        let tail = &line[i - (marker_length - 1)..i - (marker_length - 2)];
+        lower_letter_count(&mut letter_counter, tail);
+    }
+
+    Err(Error::new(format!(
+        "no marker of {} different characters found",
+        marker_length
+    )))
+}
+
+fn raise_letter_count<'a>(letter_counter: &mut HashMap<&'a str, usize>, letter: &'a str) {
+    match letter_counter.get_mut(letter) {
+        Some(value) => {
+            *value += 1;
+        }
+        None => {
+            letter_counter.insert(letter, 1);
+        }
+    };
+}
+
+fn lower_letter_count<'a>(letter_counter: &mut HashMap<&'a str, usize>, letter: &'a str) {
+    match letter_counter.get_mut(letter) {
+        Some(value) => {
+            *value -= 1;
+        }
+        None => panic!("Letter is not inserted: {}", letter),
+    };
+}
+
+fn is_only_unique_letters(letter_counter: &HashMap<&str, usize>) -> bool {
+    for value in letter_counter.values() {
+        if *value > 1 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Generates a signal of `size` characters, at least 28. The part before a
+/// random position uses only three letters and 14 distinct letters are planted
+/// there, so both markers are found at the latest within the planted letters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let length = size.max(28);
+    let marker_position = rng.range(0..=length - 14);
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+
+    let mut signal = String::with_capacity(length + 2);
+    for _ in 0..marker_position {
+        signal.push(char::from(*rng.pick(&letters[..3])));
+    }
+    signal.extend(letters[..14].iter().map(|letter| char::from(*letter)));
+    while signal.len() < length {
+        signal.push(char::from(*rng.pick(&letters)));
+    }
+    signal.push_str("\n\n");
+
+    signal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLES: [(&str, i64, i64); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn example_star_1() {
+        for (signal, star_1, _) in EXAMPLES {
+            let day = Day6::from_input(signal).unwrap();
+            assert_eq!(
+                day.part_one().unwrap(),
+                Answer::Number(star_1),
+                "{}",
+                signal
+            );
+        }
+    }
+
+    #[test]
+    fn example_star_2() {
+        for (signal, _, star_2) in EXAMPLES {
+            let day = Day6::from_input(signal).unwrap();
+            assert_eq!(
+                day.part_two().unwrap(),
+                Answer::Number(star_2),
+                "{}",
+                signal
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day6::from_input(input).unwrap();
+            let (Answer::Number(packet), Answer::Number(message)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //Only three letters come before the 14 planted ones, which start with
+            //these three, so the fourth distinct letter is the fourth planted one
+            //and every earlier window repeats one of the three
+            let mut seen = Vec::new();
+            let fourth_letter = input
+                .chars()
+                .position(|letter| {
+                    if !seen.contains(&letter) {
+                        seen.push(letter);
+                    }
+                    seen.len() == 4
+                })
+                .unwrap();
+            let planted = (fourth_letter - 3) as i64;
+            assert_eq!(packet, planted + 4);
+            assert_eq!(message, planted + 14);
+        });
+    }
+}
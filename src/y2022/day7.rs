@@ -0,0 +1,498 @@
+use std::collections::HashMap;
+
+use crate::debug;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{column_of, Rng};
+
+/// A file system rebuilt from the terminal output of `cd` and `ls` commands.
+pub struct FileSystem<'a> {
+    total_space: usize,
+    root: Directory<'a>,
+    path_indices: Vec<usize>,
+}
+
+impl<'a> FileSystem<'a> {
+    fn new() -> Self {
+        let root = Directory::new("/");
+        Self {
+            total_space: 70_000_000,
+            root,
+            path_indices: vec![],
+        }
+    }
+
+    /// Replays the terminal `logs` and returns the file system they explored.
+    pub fn from_logs(logs: &[&'a str]) -> Result<Self> {
+        let mut result = FileSystem::new();
+
+        let mut line_index: usize = 0;
+        while line_index < logs.len() {
+            let line = logs[line_index];
+            if line.is_empty() {
+                line_index += 1;
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens[..] {
+                ["$", "cd", path] => result.execute_cd_command(path),
+                ["$", "ls"] => line_index += result.add_files_from_ls_logs(logs, line_index + 1)?,
+                ["$", command, ..] => {
+                    return Err(Error::new(format!("unexpected command '{}'", command))
+                        .at_column(column_of(line, command))
+                        .at_line(line_index + 1, line))
+                }
+                _ => {
+                    return Err(Error::new("expected a command starting with '$'")
+                        .at_column(1)
+                        .at_line(line_index + 1, line))
+                }
+            }
+
+            line_index += 1;
+        }
+
+        result.change_to_root();
+        Ok(result)
+    }
+
+    fn execute_cd_command(&mut self, path: &'a str) {
+        match path {
+            ".." => self.change_to_prev_dir(),
+            "/" => self.change_to_root(),
+            dir_name => self.change_dir(dir_name),
+        }
+    }
+
+    fn add_files_from_ls_logs(&mut self, logs: &[&'a str], first_index: usize) -> Result<usize> {
+        let mut ls_output_offset: usize = 0;
+
+        while let Some(line) = logs.get(first_index + ls_output_offset) {
+            let ls_line_tokens: Vec<&str> = line.split_whitespace().collect();
+            match ls_line_tokens[..] {
+                [] | ["$", ..] => break,
+                ["dir", name] => self.add_sub_dir(Directory::new(name)),
+                [size, name] => match size.parse() {
+                    Ok(size) => self.add_file(File::new(size, name)),
+                    Err(why) => {
+                        return Err(Error::new(format!("invalid file size '{}': {}", size, why))
+                            .at_column(column_of(line, size))
+                            .at_line(first_index + ls_output_offset + 1, line))
+                    }
+                },
+                _ => {
+                    return Err(Error::new("expected 'dir <name>' or '<size> <name>'")
+                        .at_line(first_index + ls_output_offset + 1, line))
+                }
+            }
+            ls_output_offset += 1;
+        }
+
+        Ok(ls_output_offset)
+    }
+
+    fn current_dir_mut(&mut self) -> &mut Directory<'a> {
+        let mut current_dir = &mut self.root;
+        for i in self.path_indices.iter() {
+            current_dir = &mut current_dir.sub_dirs[*i];
+        }
+
+        current_dir
+    }
+
+    fn current_dir(&self) -> &Directory<'a> {
+        let mut current_dir = &self.root;
+        for i in self.path_indices.iter() {
+            current_dir = &current_dir.sub_dirs[*i];
+        }
+
+        current_dir
+    }
+
+    fn change_dir(&mut self, name: &str) {
+        let index = match self.current_dir().sub_dirs_pos.get(name) {
+            Some(position) => position,
+            None => return,
+        };
+
+        self.path_indices.push(*index);
+    }
+
+    fn change_to_prev_dir(&mut self) {
+        if !self.path_indices.is_empty() {
+            self.path_indices.pop();
+        }
+    }
+
+    fn change_to_root(&mut self) {
+        self.path_indices.clear();
+    }
+
+    fn add_sub_dir(&mut self, dir: Directory<'a>) {
+        let current_dir = self.current_dir_mut();
+
+        if current_dir.is_file_or_sub_dir_existing_with_name(dir.name) {
+            return;
+        }
+
+        current_dir
+            .sub_dirs_pos
+            .insert(dir.name, current_dir.sub_dirs.len());
+        current_dir.sub_dirs.push(dir);
+    }
+
+    fn add_file(&mut self, file: File<'a>) {
+        let current_dir = self.current_dir_mut();
+
+        if current_dir.is_file_or_sub_dir_existing_with_name(file.name) {
+            return;
+        }
+
+        current_dir
+            .files_pos
+            .insert(file.name, current_dir.files.len());
+        current_dir.files.push(file);
+    }
+
+    pub fn root(&self) -> &Directory<'a> {
+        &self.root
+    }
+
+    /// Lists the current directory tree the way the puzzle text shows it.
+    pub fn recursive_list(&self) -> String {
+        let mut result = String::from("");
+        let current_dir = self.current_dir();
+        current_dir.recursive_list(0, &mut result);
+
+        result
+    }
+
+    /// Sum of the total sizes of all directories of at most `max_small_dir_size`.
+    pub fn sizes_of_small_dirs(&self, max_small_dir_size: usize) -> usize {
+        let mut sum = 0;
+        FileSystem::add_small_dir_size(&self.root, &mut sum, &max_small_dir_size);
+        sum
+    }
+
+    fn add_small_dir_size(dir: &Directory, sum: &mut usize, max_small_dir_size: &usize) {
+        let dir_size = dir.total_size();
+        if dir_size <= *max_small_dir_size {
+            *sum += dir_size;
+        }
+
+        for sub_dir in dir.sub_dirs.iter() {
+            FileSystem::add_small_dir_size(sub_dir, sum, max_small_dir_size);
+        }
+    }
+
+    /// Size of the smallest directory that has to be deleted to have
+    /// `needed_space` free, `None` if no directory is big enough.
+    pub fn free_up_space(&self, needed_space: usize) -> Option<usize> {
+        let mut big_enough_sizes: Vec<usize> = Vec::new();
+        let free_space = self.total_space.checked_sub(self.root.total_size())?;
+        let needed_space = needed_space.saturating_sub(free_space);
+        FileSystem::add_big_enough_dir_size(&self.root, &mut big_enough_sizes, needed_space);
+
+        big_enough_sizes.iter().min().copied()
+    }
+
+    fn add_big_enough_dir_size(
+        dir: &Directory,
+        big_enough_sizes: &mut Vec<usize>,
+        needed_space: usize,
+    ) {
+        let dir_size = dir.total_size();
+        if dir_size >= needed_space {
+            big_enough_sizes.push(dir_size);
+        }
+
+        for sub_dir in dir.sub_dirs.iter() {
+            FileSystem::add_big_enough_dir_size(sub_dir, big_enough_sizes, needed_space);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Directory<'a> {
+    name: &'a str,
+    files: Vec<File<'a>>,
+    files_pos: HashMap<&'a str, usize>,
+    sub_dirs: Vec<Directory<'a>>,
+    sub_dirs_pos: HashMap<&'a str, usize>,
+}
+
+impl<'a> Directory<'a> {
+    fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            files: Vec::new(),
+            files_pos: HashMap::new(),
+            sub_dirs: Vec::new(),
+            sub_dirs_pos: HashMap::new(),
+        }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn files(&self) -> &[File<'a>] {
+        &self.files
+    }
+
+    pub fn sub_dirs(&self) -> &[Directory<'a>] {
+        &self.sub_dirs
+    }
+
+    /// Size of all files in this directory and its sub directories.
+    pub fn total_size(&self) -> usize {
+        let mut result = 0;
+
+        for file in self.files.iter() {
+            result += file.size;
+        }
+
+        for sub_dir in self.sub_dirs.iter() {
+            result += sub_dir.total_size();
+        }
+
+        result
+    }
+
+    fn is_file_or_sub_dir_existing_with_name(&self, name: &str) -> bool {
+        self.files_pos.contains_key(name) || self.sub_dirs_pos.contains_key(name)
+    }
+
+    fn recursive_list(&self, indent_level: usize, result: &mut String) {
+        let indent_self = "  ".repeat(indent_level);
+        result.push_str(&format!("{}- {} (dir)\n", indent_self, self.name));
+
+        let indent_sub = "  ".repeat(indent_level + 1);
+        for dir in self.sub_dirs.iter() {
+            dir.recursive_list(indent_level + 1, result);
+        }
+
+        for file in self.files.iter() {
+            result.push_str(&format!(
+                "{}- {} (file, size={})\n",
+                indent_sub, file.name, file.size
+            ));
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct File<'a> {
+    size: usize,
+    name: &'a str,
+}
+
+impl<'a> File<'a> {
+    fn new(size: usize, name: &'a str) -> Self {
+        Self { size, name }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+pub struct Day7<'a> {
+    file_system: FileSystem<'a>,
+}
+
+impl<'a> Solution<'a> for Day7<'a> {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        let file_system = FileSystem::from_logs(lines)?;
+        debug!(
+            7,
+            "file system:\n{}",
+            file_system.recursive_list().trim_end()
+        );
+
+        Ok(Self { file_system })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(self.file_system.sizes_of_small_dirs(100_000).into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        match self.file_system.free_up_space(30_000_000) {
+            Some(size) => Ok(size.into()),
+            None => Err(Error::new("no directory frees up enough space")),
+        }
+    }
+
+    fn render(&self) -> Option<Vec<String>> {
+        Some(
+            self.file_system
+                .recursive_list()
+                .lines()
+                .map(String::from)
+                .collect(),
+        )
+    }
+}
+
+const GENERATED_EXTENSIONS: [&str; 4] = [".txt", ".dat", ".log", ".lst"];
+
+/// A directory of a generated file system, its files are sizes relative to
+/// the other files.
+#[derive(Default)]
+struct GeneratedDirectory {
+    name: String,
+    sub_dirs: Vec<usize>,
+    files: Vec<(String, usize)>,
+}
+
+/// Generates the terminal output of exploring a random tree of `size` files and
+/// directories, whose total size leaves at least 1 but less than 30 million of
+/// the 70 million free, so a directory has to be deleted.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut dirs = vec![GeneratedDirectory::default()];
+    let mut total_weight = 0;
+
+    for _ in 0..size.max(1) {
+        let parent = rng.range(0..=dirs.len() - 1);
+        let is_dir = rng.chance(25);
+        let name = loop {
+            let mut name: String = (0..rng.range(1..=8))
+                .map(|_| char::from(*rng.pick(b"abcdefghijklmnopqrstuvwxyz")))
+                .collect();
+            if !is_dir && rng.chance(50) {
+                let extension = rng.pick(&GENERATED_EXTENSIONS);
+                name.push_str(extension);
+            }
+
+            let parent_dir = &dirs[parent];
+            let is_taken = parent_dir.files.iter().any(|(file, _)| *file == name)
+                || parent_dir
+                    .sub_dirs
+                    .iter()
+                    .any(|dir| dirs[*dir].name == name);
+            if !is_taken {
+                break name;
+            }
+        };
+
+        if is_dir {
+            dirs.push(GeneratedDirectory {
+                name,
+                ..GeneratedDirectory::default()
+            });
+            let index = dirs.len() - 1;
+            dirs[parent].sub_dirs.push(index);
+        } else {
+            let weight = rng.range(1..=1_000);
+            dirs[parent].files.push((name, weight));
+            total_weight += weight;
+        }
+    }
+
+    if total_weight == 0 {
+        dirs[0].files.push((String::from("a"), 1));
+        total_weight = 1;
+    }
+
+    //Every weight unit gets the same size, so the total is a multiple of it
+    let size_per_weight =
+        rng.range(40_000_001_usize.div_ceil(total_weight)..=69_000_000 / total_weight);
+    let mut input = String::from("$ cd /\n");
+    write_generated_dir(&dirs, 0, size_per_weight, &mut input);
+    input.push('\n');
+
+    input
+}
+
+fn write_generated_dir(
+    dirs: &[GeneratedDirectory],
+    index: usize,
+    size_per_weight: usize,
+    input: &mut String,
+) {
+    let dir = &dirs[index];
+    input.push_str("$ ls\n");
+    for sub_dir in dir.sub_dirs.iter() {
+        input.push_str(&format!("dir {}\n", dirs[*sub_dir].name));
+    }
+    for (name, weight) in dir.files.iter() {
+        input.push_str(&format!("{} {}\n", weight * size_per_weight, name));
+    }
+
+    for sub_dir in dir.sub_dirs.iter() {
+        input.push_str(&format!("$ cd {}\n", dirs[*sub_dir].name));
+        write_generated_dir(dirs, *sub_dir, size_per_weight, input);
+        input.push_str("$ cd ..\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day7::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(95437));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day7::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(24933642));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day7::from_input(input).unwrap();
+            let used: i64 = input
+                .lines()
+                .filter_map(|line| line.split_whitespace().next()?.parse::<i64>().ok())
+                .sum();
+
+            //The generator leaves at least 1,000,000 but less than 30,000,000
+            //of the 70,000,000 free, so a directory has to be deleted
+            day.part_one().unwrap();
+            let Answer::Number(deleted) = day.part_two().unwrap() else {
+                panic!("expected a number");
+            };
+            assert!((40_000_001..=69_000_000).contains(&used));
+            assert!((used - 40_000_000..=used).contains(&deleted));
+        });
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{Direction, Grid, Rng};
+
+pub struct Day8 {
+    trees: Grid<u8>,
+}
+
+impl<'a> Solution<'a> for Day8 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        if lines.is_empty() || lines[0].is_empty() {
+            return Err(Error::new("expected a grid of trees"));
+        }
+
+        Ok(Self {
+            trees: Grid::parse(lines, parse_tree_height)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        let trees = &self.trees;
+        let width = trees.width();
+        let height = trees.height();
+
+        let mut visible_inner_trees: HashMap<(usize, usize), bool> =
+            HashMap::with_capacity(width * height);
+
+        //find any trees visible from top
+        for col in 1..(width - 1) {
+            let mut highest_tree = trees[(col, 0)];
+            for row in 1..(height - 1) {
+                let current_tree = trees[(col, row)];
+                if current_tree > highest_tree {
+                    highest_tree = current_tree;
+                    visible_inner_trees.insert((col, row), true);
+                }
+            }
+        }
+
+        //find any trees visible from bottom
+        for col in 1..(width - 1) {
+            let mut highest_tree = trees[(col, height - 1)];
+            for row in (1..(height - 1)).rev() {
+                let current_tree = trees[(col, row)];
+                if current_tree > highest_tree {
+                    highest_tree = current_tree;
+                    visible_inner_trees.insert((col, row), true);
+                }
+            }
+        }
+
+        //find any trees visible from left
+        for row in 1..(height - 1) {
+            let mut highest_tree = trees[(0, row)];
+            for col in 1..(width - 1) {
+                let current_tree = trees[(col, row)];
+                if current_tree > highest_tree {
+                    highest_tree = current_tree;
+                    visible_inner_trees.insert((col, row), true);
+                }
+            }
+        }
+
+        //find any trees visible from right
+        for row in 1..(height - 1) {
+            let mut highest_tree = trees[(width - 1, row)];
+            for col in (1..(width - 1)).rev() {
+                let current_tree = trees[(col, row)];
+                if current_tree > highest_tree {
+                    highest_tree = current_tree;
+                    visible_inner_trees.insert((col, row), true);
+                }
+            }
+        }
+
+        //A single row or column has no inner trees, all of them are outer trees
+        let visible_outer_trees =
+            width * height - width.saturating_sub(2) * height.saturating_sub(2);
+        Ok((visible_inner_trees.keys().len() + visible_outer_trees).into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        let mut max_scenic_score = 0;
+        for index in 0..self.trees.cells().len() {
+            let current_scenic_score = calc_scenic_score(&self.trees, index);
+
+            if current_scenic_score > max_scenic_score {
+                max_scenic_score = current_scenic_score;
+            }
+        }
+
+        Ok(max_scenic_score.into())
+    }
+}
+
+/// Product of the viewing distances in all four directions, trees on the edge
+/// see nothing in one of them and score 0.
+fn calc_scenic_score(trees: &Grid<u8>, index: usize) -> u64 {
+    let current_tree = trees[index];
+    let mut score = 1;
+
+    for direction in Direction::ALL {
+        let mut viewing_distance = 0;
+        for other_index in trees.ray(index, direction.step()) {
+            viewing_distance += 1;
+            if trees[other_index] >= current_tree {
+                break;
+            }
+        }
+
+        score *= viewing_distance;
+    }
+
+    score
+}
+
+fn parse_tree_height(tree: char) -> Result<u8> {
+    match tree.to_digit(10) {
+        Some(height) => Ok(height as u8),
+        None => Err(Error::new(format!("unexpected tree height '{}'", tree))),
+    }
+}
+
+/// Generates a square grid of tree heights with `size` rows.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut input = String::with_capacity((side + 1) * side + 1);
+
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(char::from(*rng.pick(b"0123456789")));
+        }
+        input.push('\n');
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day8::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(21));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day8::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(8));
+    }
+
+    #[test]
+    fn single_row_is_visible() {
+        for (input, visible) in [("8\n\n", 1), ("303\n\n", 3)] {
+            let day = Day8::from_input(input).unwrap();
+            assert_eq!(
+                day.part_one().unwrap(),
+                Answer::Number(visible),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day8::from_input(input).unwrap();
+            let (Answer::Number(visible), Answer::Number(best_score)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //Every tree on the edge of the 50 by 50 grid is visible, every
+            //inner tree sees at least one tree in each direction and at most
+            //24 * 25 trees along each axis
+            assert!((4 * 49..=50 * 50).contains(&visible));
+            assert!((1..=(24 * 25) * (24 * 25)).contains(&best_score));
+        });
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::util::{column_of, parse_lines, Direction, Point, Rng};
+use crate::{debug, trace};
+
+struct Command {
+    dir: Direction,
+    amount: u8,
+}
+
+impl Command {
+    fn from_line(line: &str) -> Result<Self> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        if tokens.len() != 2 {
+            return Err(Error::new(format!(
+                "expected a motion like 'R 4' but found {} tokens",
+                tokens.len()
+            )));
+        }
+
+        let dir = match tokens[0] {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => {
+                return Err(Error::new(format!("unexpected direction '{}'", tokens[0]))
+                    .at_column(column_of(line, tokens[0])))
+            }
+        };
+
+        let amount = match tokens[1].parse::<u8>() {
+            Ok(value) => value,
+            Err(why) => {
+                return Err(
+                    Error::new(format!("invalid amount '{}': {}", tokens[1], why))
+                        .at_column(column_of(line, tokens[1])),
+                )
+            }
+        };
+
+        Ok(Self { dir, amount })
+    }
+}
+
+struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    fn new(knot_count: usize) -> Self {
+        if knot_count < 2 {
+            panic!("Rope needs atleast two knots");
+        }
+
+        Self {
+            knots: vec![Point::ORIGIN; knot_count],
+        }
+    }
+
+    fn move_in_direction(&mut self, direction: Direction) {
+        *self.head_mut() += direction.step();
+
+        for current_knot_index in 1..self.knots.len() {
+            let prev_knot = self.knots[current_knot_index - 1];
+            let current_knot = &mut self.knots[current_knot_index];
+
+            //Touching knots, diagonals included, stay where they are
+            if current_knot.chebyshev_distance(prev_knot) < 2 {
+                break;
+            }
+
+            *current_knot += (prev_knot - *current_knot).signum();
+            trace!(9, "knot {} moved to {}", current_knot_index, current_knot);
+        }
+    }
+
+    fn tail(&self) -> Point {
+        *self.knots.last().unwrap()
+    }
+
+    fn head_mut(&mut self) -> &mut Point {
+        &mut self.knots[0]
+    }
+}
+
+pub struct Day9 {
+    commands: Vec<Command>,
+}
+
+impl<'a> Solution<'a> for Day9 {
+    fn parse(lines: &[&'a str]) -> Result<Self> {
+        Ok(Self {
+            commands: parse_lines(lines, Command::from_line)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer> {
+        Ok(count_tail_positions(&self.commands, 2).into())
+    }
+
+    fn part_two(&self) -> Result<Answer> {
+        Ok(count_tail_positions(&self.commands, 10).into())
+    }
+}
+
+fn count_tail_positions(commands: &[Command], knot_count: usize) -> usize {
+    let mut rope = Rope::new(knot_count);
+    let mut tail_positions: HashMap<Point, bool> = HashMap::new();
+
+    for command in commands {
+        for _ in 0..command.amount {
+            rope.move_in_direction(command.dir);
+            tail_positions.insert(rope.tail(), true);
+        }
+    }
+    debug!(
+        9,
+        "the tail of {} knots visited {} positions",
+        knot_count,
+        tail_positions.len()
+    );
+
+    tail_positions.keys().len()
+}
+
+/// Generates `size` motions of the head of up to 20 steps each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        input.push_str(&format!(
+            "{} {}\n",
+            rng.pick(&['U', 'D', 'L', 'R']),
+            rng.range(1..=20)
+        ));
+    }
+    input.push('\n');
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::check_generated_inputs;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+
+";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+
+";
+
+    #[test]
+    fn example_star_1() {
+        let day = Day9::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(13));
+    }
+
+    #[test]
+    fn example_star_2() {
+        let day = Day9::from_input(EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(1));
+
+        let day = Day9::from_input(LARGER_EXAMPLE).unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(36));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generated_inputs(10, 50, generate, |input| {
+            let day = Day9::from_input(input).unwrap();
+            let steps: i64 = input
+                .lines()
+                .filter_map(|line| line.split_whitespace().nth(1)?.parse::<i64>().ok())
+                .sum();
+            let (Answer::Number(short_rope), Answer::Number(long_rope)) =
+                (day.part_one().unwrap(), day.part_two().unwrap())
+            else {
+                panic!("expected numbers");
+            };
+
+            //A tail moves at most once per step and the longer rope lags behind
+            assert!((1..=steps + 1).contains(&short_rope));
+            assert!((1..=short_rope).contains(&long_rope));
+        });
+    }
+}
@@ -0,0 +1,348 @@
+//! Differential tests running the production solvers and naive reference
+//! solvers on generated inputs. The first input they disagree on is shrunk by
+//! removing lines as long as the disagreement persists.
+
+use std::collections::VecDeque;
+
+use advent_of_code::solution::{Answer, Solution};
+use advent_of_code::util::Rng;
+use advent_of_code::y2022::day12::{self, Day12};
+use advent_of_code::y2022::day5::{self, Day5};
+use advent_of_code::y2022::day8::{self, Day8};
+
+/// Answers of both stars, `None` for a star that could not be solved.
+type Answers = [Option<Answer>; 2];
+
+/// A solver gives `None` if it can not read the input at all.
+type Solver = fn(&str) -> Option<Answers>;
+
+/// Solves both stars of an input with the production solver of a day.
+fn production<T: for<'a> Solution<'a>>(input: &str) -> Option<Answers> {
+    let day = T::from_input(input).ok()?;
+    Some([day.part_one().ok(), day.part_two().ok()])
+}
+
+/// Answers of both solvers if they disagree. Only an input the reference can
+/// not read counts as agreement, production rejecting an input the reference
+/// reads is a disagreement with `None` as its answers.
+fn disagreement(
+    input: &str,
+    actual: Solver,
+    expected: Solver,
+) -> Option<(Option<Answers>, Answers)> {
+    let expected = expected(input)?;
+    let actual = actual(input);
+    if actual.as_ref() == Some(&expected) {
+        return None;
+    }
+
+    Some((actual, expected))
+}
+
+/// Removes single lines of `input` as long as the solvers still disagree.
+/// Blank lines are kept, they separate the blocks of an input.
+fn minimize(input: &str, actual: Solver, expected: Solver) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut index = 0;
+    while index < lines.len() {
+        if lines[index].is_empty() {
+            index += 1;
+            continue;
+        }
+
+        let mut candidate = lines.clone();
+        candidate.remove(index);
+        if disagreement(&join(&candidate), actual, expected).is_some() {
+            //Removing a line can make an earlier line removable, start again
+            lines = candidate;
+            index = 0;
+        } else {
+            index += 1;
+        }
+    }
+
+    join(&lines)
+}
+
+fn join(lines: &[&str]) -> String {
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// Compares both solvers on `runs` generated inputs, the size of an input is
+/// picked by `size` from its seed. Panics with the minimized input of the
+/// first disagreement.
+fn compare(
+    generate: fn(&mut Rng, usize) -> String,
+    runs: u64,
+    size: fn(u64) -> usize,
+    actual: Solver,
+    expected: Solver,
+) {
+    for seed in 0..runs {
+        let input = generate(&mut Rng::new(seed), size(seed));
+        if disagreement(&input, actual, expected).is_none() {
+            continue;
+        }
+
+        let minimized = minimize(&input, actual, expected);
+        let (actual, expected) = disagreement(&minimized, actual, expected).unwrap();
+        panic!(
+            "solvers disagree on the input of seed {}, minimized to:\n{}\nproduction: {:?}\nreference:  {:?}",
+            seed, minimized, actual, expected
+        );
+    }
+}
+
+/// Rows of a rectangular grid of bytes, without blank lines.
+fn grid(input: &str) -> Option<Vec<&[u8]>> {
+    let rows: Vec<&[u8]> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::as_bytes)
+        .collect();
+    if rows.is_empty() || rows.iter().any(|row| row.len() != rows[0].len()) {
+        return None;
+    }
+
+    Some(rows)
+}
+
+/// Moves crates one at a time for the first star and whole piles for the
+/// second one.
+fn reference_day5(input: &str) -> Option<Answers> {
+    let (drawing, moves) = input.split_once("\n\n")?;
+    let mut drawing: Vec<&str> = drawing.lines().collect();
+    let stack_count = drawing.pop()?.split_whitespace().count();
+
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); stack_count];
+    for line in drawing.iter().rev() {
+        for (number, stack) in stacks.iter_mut().enumerate() {
+            match line.chars().nth(1 + 4 * number) {
+                Some(letter) if letter.is_ascii_uppercase() => stack.push(letter),
+                _ => {}
+            }
+        }
+    }
+
+    let mut steps = Vec::new();
+    for line in moves.lines().filter(|line| !line.is_empty()) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let ["move", count, "from", from, "to", to] = words[..] else {
+            return None;
+        };
+        let from = from.parse::<usize>().ok()?.checked_sub(1)?;
+        let to = to.parse::<usize>().ok()?.checked_sub(1)?;
+        if from >= stack_count || to >= stack_count {
+            return None;
+        }
+        steps.push((count.parse::<usize>().ok()?, from, to));
+    }
+
+    let one_at_a_time = |mut stacks: Vec<Vec<char>>| {
+        for (count, from, to) in steps.iter() {
+            for _ in 0..*count {
+                let letter = stacks[*from].pop()?;
+                stacks[*to].push(letter);
+            }
+        }
+        Some(tops(&stacks))
+    };
+    let whole_piles = |mut stacks: Vec<Vec<char>>| {
+        for (count, from, to) in steps.iter() {
+            let at = stacks[*from].len().checked_sub(*count)?;
+            let pile = stacks[*from].split_off(at);
+            stacks[*to].extend(pile);
+        }
+        Some(tops(&stacks))
+    };
+
+    Some([one_at_a_time(stacks.clone()), whole_piles(stacks)])
+}
+
+fn tops(stacks: &[Vec<char>]) -> Answer {
+    let tops: String = stacks.iter().filter_map(|stack| stack.last()).collect();
+    tops.into()
+}
+
+/// Looks from every tree in all four directions.
+fn reference_day8(input: &str) -> Option<Answers> {
+    let rows = grid(input)?;
+    if rows
+        .iter()
+        .flat_map(|row| row.iter())
+        .any(|tree| !tree.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let (width, height) = (rows[0].len() as i64, rows.len() as i64);
+    let mut visible: usize = 0;
+    let mut best_score: usize = 0;
+    for y in 0..height {
+        for x in 0..width {
+            let tree = rows[y as usize][x as usize];
+            let mut is_visible = false;
+            let mut score = 1;
+            for (step_x, step_y) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let (mut other_x, mut other_y) = (x + step_x, y + step_y);
+                let mut viewing_distance = 0;
+                let mut blocked = false;
+                while (0..width).contains(&other_x) && (0..height).contains(&other_y) {
+                    viewing_distance += 1;
+                    if rows[other_y as usize][other_x as usize] >= tree {
+                        blocked = true;
+                        break;
+                    }
+                    other_x += step_x;
+                    other_y += step_y;
+                }
+                is_visible |= !blocked;
+                score *= viewing_distance;
+            }
+
+            if is_visible {
+                visible += 1;
+            }
+            best_score = best_score.max(score);
+        }
+    }
+
+    Some([Some(visible.into()), Some(best_score.into())])
+}
+
+/// Breadth first search downhill from the end, which gives the steps from
+/// every square at once.
+fn reference_day12(input: &str) -> Option<Answers> {
+    let rows = grid(input)?;
+    let (width, height) = (rows[0].len(), rows.len());
+    let squares: Vec<u8> = rows.concat();
+    if squares.iter().filter(|square| **square == b'S').count() != 1
+        || squares.iter().filter(|square| **square == b'E').count() != 1
+        || squares
+            .iter()
+            .any(|square| !square.is_ascii_lowercase() && *square != b'S' && *square != b'E')
+    {
+        return None;
+    }
+
+    let elevation = |square: u8| match square {
+        b'S' => b'a',
+        b'E' => b'z',
+        _ => square,
+    };
+    let start = squares.iter().position(|square| *square == b'S')?;
+    let end = squares.iter().position(|square| *square == b'E')?;
+
+    let mut steps: Vec<Option<usize>> = vec![None; squares.len()];
+    let mut queue = VecDeque::from([end]);
+    steps[end] = Some(0);
+    while let Some(index) = queue.pop_front() {
+        let (x, y) = (index % width, index / width);
+        let mut neighbours = Vec::with_capacity(4);
+        if x > 0 {
+            neighbours.push(index - 1);
+        }
+        if x + 1 < width {
+            neighbours.push(index + 1);
+        }
+        if y > 0 {
+            neighbours.push(index - width);
+        }
+        if y + 1 < height {
+            neighbours.push(index + width);
+        }
+
+        for neighbour in neighbours {
+            //Walking backwards, the neighbour climbs at most one up to here
+            let climbable = elevation(squares[index]) <= elevation(squares[neighbour]) + 1;
+            if climbable && steps[neighbour].is_none() {
+                steps[neighbour] = Some(steps[index]? + 1);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    let fewest_from_lowest = (0..squares.len())
+        .filter(|index| elevation(squares[*index]) == b'a')
+        .filter_map(|index| steps[index])
+        .min();
+
+    Some([
+        steps[start].map(Answer::from),
+        fewest_from_lowest.map(Answer::from),
+    ])
+}
+
+#[test]
+fn day5_matches_plain_stack_moves() {
+    compare(
+        day5::generate,
+        3000,
+        |seed| 1 + seed as usize % 30,
+        production::<Day5>,
+        reference_day5,
+    );
+}
+
+#[test]
+fn day8_matches_brute_force_visibility() {
+    compare(
+        day8::generate,
+        3000,
+        |seed| 1 + seed as usize % 12,
+        production::<Day8>,
+        reference_day8,
+    );
+}
+
+#[test]
+fn day12_matches_breadth_first_search() {
+    compare(
+        day12::generate,
+        1000,
+        |_| 26,
+        production::<Day12>,
+        reference_day12,
+    );
+}
+
+#[test]
+fn minimize_keeps_only_the_disagreeing_line() {
+    fn counts_lines(input: &str) -> Option<Answers> {
+        Some([Some(input.lines().count().into()), None])
+    }
+    fn miscounts_lines(input: &str) -> Option<Answers> {
+        let count = input.lines().count() + usize::from(input.contains("bad"));
+        Some([Some(count.into()), None])
+    }
+
+    let minimized = minimize("first\nbad line\n\nlast\n", miscounts_lines, counts_lines);
+
+    assert_eq!(minimized, "bad line\n\n");
+}
+
+#[test]
+fn rejected_inputs_disagree() {
+    fn counts_lines(input: &str) -> Option<Answers> {
+        Some([Some(input.lines().count().into()), None])
+    }
+    fn rejects_bad_lines(input: &str) -> Option<Answers> {
+        if input.contains("bad") {
+            return None;
+        }
+        counts_lines(input)
+    }
+
+    assert!(disagreement("good\n", rejects_bad_lines, counts_lines).is_none());
+    assert!(disagreement("bad\n", counts_lines, rejects_bad_lines).is_none());
+    assert_eq!(
+        disagreement("bad\n", rejects_bad_lines, counts_lines),
+        Some((None, [Some(Answer::Number(1)), None]))
+    );
+    assert_eq!(
+        minimize("first\nbad line\n\nlast\n", rejects_bad_lines, counts_lines),
+        "bad line\n\n"
+    );
+}
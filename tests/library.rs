@@ -0,0 +1,381 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{env, fs, process, thread};
+
+use advent_of_code::cli::{parse_args, CliError, Command};
+use advent_of_code::fetch::{fetch_input, Endpoint, Fetched};
+use advent_of_code::runner::{available_days, batch, run_days, Day, YEARS};
+use advent_of_code::scaffold::add_day;
+use advent_of_code::solution::Star;
+use advent_of_code::util::{InputLocator, Point, Rng};
+use advent_of_code::y2022;
+use advent_of_code::y2022::day10::{Device, Instruction};
+use advent_of_code::y2022::day12::HeightMap;
+use advent_of_code::y2022::day7::FileSystem;
+
+fn lines(text: &str) -> Vec<&str> {
+    text.lines().collect()
+}
+
+#[test]
+fn file_system_from_terminal_logs() {
+    let logs = lines(
+        "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+$ cd a
+$ ls
+584 i
+62596 h.lst",
+    );
+
+    let file_system = FileSystem::from_logs(&logs).unwrap();
+    let root = file_system.root();
+
+    assert_eq!(root.total_size(), 14848514 + 584 + 62596);
+    assert_eq!(root.sub_dirs()[0].name(), "a");
+    assert_eq!(root.sub_dirs()[0].files()[1].size(), 62596);
+    assert_eq!(file_system.sizes_of_small_dirs(100_000), 584 + 62596);
+    assert_eq!(
+        file_system.recursive_list(),
+        "\
+- / (dir)
+  - a (dir)
+    - i (file, size=584)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+"
+    );
+}
+
+#[test]
+fn device_executes_instructions() {
+    let mut device = Device::new();
+    for line in ["noop", "addx 3", "addx -5"] {
+        device.execute_instruction(&Instruction::from_line(line).unwrap());
+    }
+
+    assert_eq!(device.cycle_count(), 5);
+    assert_eq!(device.register_x(), -1);
+    assert_eq!(
+        device.render_screen()[0],
+        format!("#####{}", ".".repeat(35))
+    );
+}
+
+#[test]
+fn height_map_shortest_path() {
+    let height_map = HeightMap::parse_height_map(&lines(
+        "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+    ))
+    .unwrap();
+
+    let path = height_map.find_shortest_path(height_map.start()).unwrap();
+
+    assert_eq!(path.len(), 31);
+    assert_eq!(path.last(), Some(&height_map.end()));
+    assert_eq!(height_map.end(), Point::new(5, 2));
+    assert_eq!(height_map.find_all_possible_starting_positions().len(), 6);
+}
+
+#[test]
+fn every_day_ignores_line_endings() {
+    for day in YEARS.iter().flat_map(|year| year.days) {
+        let input = day.generate(&mut Rng::new(7), 8);
+        let solve = |text: &str| {
+            let solution = day.parse(text).unwrap();
+            [Star::One, Star::Two].map(|star| solution.solve(star).map_err(|why| why.to_string()))
+        };
+        let expected = solve(&input);
+
+        let trimmed = input.trim_end_matches('\n');
+        for variant in [
+            String::from(trimmed),
+            format!("\u{feff}{}", input),
+            input.replace('\n', "\r\n"),
+            input.replace('\n', " \t\n"),
+        ] {
+            assert_eq!(
+                solve(&variant),
+                expected,
+                "day {}: {:?}",
+                day.number,
+                variant
+            );
+        }
+    }
+}
+
+#[test]
+fn malformed_lines_are_errors() {
+    for (number, input, expected) in [
+        (
+            1,
+            "4294967295\n1\n",
+            "line 2: calorie total of the elve overflows\n    1",
+        ),
+        (2, "A Y\n\nB X\n", "line 2: unexpected empty line"),
+        (2, "Aé\n", "line 1: expected a round like 'A X'\n    Aé"),
+        (2, "é Y\n", "line 1: unexpected choice 'é'\n    é Y\n    ^"),
+        (
+            3,
+            "vJrwpWtwJgWrhcsFMMfFFhFp\n\nPmmdzqPrV\n",
+            "line 2: unexpected empty line",
+        ),
+        (4, "2-4,6-8\n\nfoo\n", "line 2: unexpected empty line"),
+    ] {
+        let day = &y2022::DAYS[number - 1];
+        let Err(why) = day.parse(input) else {
+            panic!("day {}: expected an error for {:?}", number, input);
+        };
+        assert_eq!(why.to_string(), expected, "day {}", number);
+    }
+}
+
+#[test]
+fn new_day_is_registered_and_never_overwritten() {
+    let src_dir = env::temp_dir().join(format!("advent_of_code_new_{}", process::id()));
+    fs::create_dir_all(src_dir.join("y2022")).unwrap();
+    let lib = "//! Crate\n\npub mod util;\npub mod y2022;\n";
+    fs::write(src_dir.join("lib.rs"), lib).unwrap();
+    fs::write(
+        src_dir.join("runner.rs"),
+        "pub const YEARS: &[Year] = &[Year::new(2022, crate::y2022::DAYS)];\n",
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("y2022").join("mod.rs"),
+        "use crate::day;\n\npub mod day1;\npub mod day10;\npub mod day2;\n\n\
+         pub const DAYS: &[Day] = &[day!(1, day1::Day1), day!(2, day2::Day2)];\n",
+    )
+    .unwrap();
+    let input_path = src_dir.join("inputs").join("2022").join("day3.input");
+
+    let written = add_day(&src_dir, 2022, 3, &input_path).unwrap();
+
+    assert_eq!(written.len(), 3);
+    let year_module = fs::read_to_string(src_dir.join("y2022").join("mod.rs")).unwrap();
+    assert_eq!(
+        year_module,
+        "use crate::day;\n\npub mod day1;\npub mod day10;\npub mod day2;\npub mod day3;\n\n\
+         pub const DAYS: &[Day] = &[\n    day!(1, day1::Day1),\n    day!(2, day2::Day2),\n    \
+         day!(3, day3::Day3),\n];\n"
+    );
+    let module = fs::read_to_string(src_dir.join("y2022").join("day3.rs")).unwrap();
+    assert!(module.contains("impl<'a> Solution<'a> for Day3<'a>"));
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), "");
+    assert_eq!(fs::read_to_string(src_dir.join("lib.rs")).unwrap(), lib);
+
+    let why = add_day(&src_dir, 2022, 3, &input_path).unwrap_err();
+    assert!(why
+        .to_string()
+        .contains("refusing to overwrite an existing module"));
+    assert_eq!(
+        fs::read_to_string(src_dir.join("y2022").join("mod.rs")).unwrap(),
+        year_module
+    );
+
+    //The first day of a new year also creates and registers its module
+    let input_path = src_dir.join("inputs").join("2023").join("day1.input");
+    let written = add_day(&src_dir, 2023, 1, &input_path).unwrap();
+
+    assert_eq!(written.len(), 5);
+    assert_eq!(
+        fs::read_to_string(src_dir.join("lib.rs")).unwrap(),
+        "//! Crate\n\npub mod util;\npub mod y2022;\npub mod y2023;\n"
+    );
+    assert_eq!(
+        fs::read_to_string(src_dir.join("runner.rs")).unwrap(),
+        "pub const YEARS: &[Year] = &[\n    Year::new(2022, crate::y2022::DAYS),\n    \
+         Year::new(2023, crate::y2023::DAYS),\n];\n"
+    );
+    assert!(fs::read_to_string(src_dir.join("y2023").join("mod.rs"))
+        .unwrap()
+        .contains("pub mod day1;\n\npub const DAYS: &[Day] = &[day!(1, day1::Day1)];\n"));
+
+    fs::remove_dir_all(&src_dir).unwrap();
+}
+
+#[test]
+fn new_rejects_days_outside_the_event() {
+    let available = [(2022, vec![1, 2])];
+    let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()), &available);
+
+    for args in [
+        ["new", "2022", "0"],
+        ["new", "2022", "99"],
+        ["fetch", "2022", "26"],
+    ] {
+        assert!(
+            matches!(parse(&args), Err(CliError::DayOutOfRange(_))),
+            "{:?}",
+            args
+        );
+    }
+    assert!(matches!(parse(&["new", "2022", "25"]), Ok(Command::New(_))));
+}
+
+#[test]
+fn batch_continues_past_failing_inputs() {
+    let dir = env::temp_dir().join(format!("advent_of_code_batch_{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("a.input"), "1000\n2000\n\n4000\n").unwrap();
+    fs::write(dir.join("b.input"), "1000\nx\n").unwrap();
+    fs::write(dir.join("c.input"), "3000\n").unwrap();
+
+    let args = ["batch", "2022", "1", dir.to_str().unwrap()].map(String::from);
+    let Ok(Command::Batch(options, batch_options)) = parse_args(args, &available_days()) else {
+        panic!("expected a batch command");
+    };
+    assert_eq!(options.days, [1]);
+    assert_eq!(batch_options.dir, dir);
+    assert!(!batch(&options, &batch_options).unwrap());
+
+    fs::remove_file(dir.join("b.input")).unwrap();
+    assert!(batch(&options, &batch_options).unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn year_selects_the_days_of_its_event() {
+    let available = [(2021, vec![1, 2]), (2022, vec![1, 2, 3])];
+    let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()), &available);
+
+    let Ok(Command::Run(options)) = parse(&["run", "2021"]) else {
+        panic!("expected a run command");
+    };
+    assert_eq!((options.year, options.days), (2021, vec![1, 2]));
+
+    let Ok(Command::Run(options)) = parse(&["run", "3"]) else {
+        panic!("expected a run command");
+    };
+    assert_eq!((options.year, options.days), (2022, vec![3]));
+
+    assert!(parse(&["run", "2021", "3"]).is_err());
+    assert!(parse(&["run", "2019"]).is_err());
+}
+
+/// Stand-in for the puzzle server, answers one request per response and
+/// returns the base URL and the header of every request it got.
+fn serve(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/aoc/", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                while reader.read_line(&mut request).unwrap() > 0 && !request.ends_with("\r\n\r\n")
+                {
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+                request
+            })
+            .collect()
+    });
+
+    (base_url, server)
+}
+
+#[test]
+fn fetch_downloads_an_input_once() {
+    let (base_url, server) = serve(vec![
+        "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n1000\n\r\n5\r\n2000\n\r\n0\r\n\r\n",
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nNot Found",
+    ]);
+    let endpoint = Endpoint {
+        base_url,
+        session: String::from("secret"),
+    };
+    let input_dir = env::temp_dir().join(format!("advent_of_code_fetch_{}", process::id()));
+    let locator = InputLocator::new(2022, Some(input_dir.clone()));
+    let path = input_dir.join("2022").join("day25.input");
+
+    assert_eq!(
+        fetch_input(&endpoint, 2022, 25, &locator).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+    assert_eq!(
+        fetch_input(&endpoint, 2022, 25, &locator).unwrap(),
+        Fetched::Cached(path.clone())
+    );
+
+    let why = fetch_input(&endpoint, 2022, 24, &locator).unwrap_err();
+    assert!(why.to_string().contains("answered '404 Not Found'"));
+    assert!(!input_dir.join("2022").join("day24.input").exists());
+
+    //The cached input was not requested again
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /aoc/2022/day/25/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+    assert!(requests[1].starts_with("GET /aoc/2022/day/24/input HTTP/1.1\r\n"));
+
+    fs::remove_dir_all(&input_dir).unwrap();
+}
+
+static LAST_DAY_PARSED: AtomicBool = AtomicBool::new(false);
+
+#[test]
+fn failing_days_do_not_stop_the_run() {
+    let days = [
+        Day::new(1, |_| panic!("overflow"), |_, _| String::new()),
+        Day::new(
+            2,
+            |_| y2022::DAYS[0].parse("1000\nx\n"),
+            |_, _| String::new(),
+        ),
+        Day::new(
+            3,
+            |text| {
+                LAST_DAY_PARSED.store(true, Ordering::Relaxed);
+                y2022::DAYS[0].parse(text)
+            },
+            |_, _| String::new(),
+        ),
+    ];
+    let input_path = env::temp_dir().join(format!("advent_of_code_run_{}.input", process::id()));
+    let mut locator = InputLocator::new(2022, None);
+    locator.add_override(None, input_path.clone());
+    fs::write(&input_path, "1000\n2000\n\n3000\n").unwrap();
+
+    for (format, jobs) in [("text", 1), ("text", 3), ("json", 1)] {
+        LAST_DAY_PARSED.store(false, Ordering::Relaxed);
+        let args = ["run", "-f", format, "-j", &jobs.to_string()].map(String::from);
+        let Ok(Command::Run(options)) = parse_args(args, &available_days()) else {
+            panic!("expected a run command");
+        };
+
+        let days: Vec<&Day> = days.iter().collect();
+        assert!(!run_days(&days, &locator, &options).unwrap(), "{}", format);
+        assert!(LAST_DAY_PARSED.load(Ordering::Relaxed), "{}", format);
+    }
+
+    fs::remove_file(&input_path).unwrap();
+}
+
+#[test]
+fn usage_errors_exit_with_2() {
+    let output = process::Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["run", "--star", "3"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("error: invalid star '3', expected 1 or 2\n\nUsage:"));
+}